@@ -7,6 +7,7 @@ use {serde_json::to_string_pretty, tokio::main};
 
 use qobuz_api_rust::{
     api::service::QobuzApiService,
+    download::DownloadOptions,
     metadata::{MetadataConfig, embedder::embed_metadata_in_file},
     utils::sanitize_filename,
 };
@@ -54,6 +55,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Create default metadata config for all downloads
     let metadata_config = MetadataConfig::default();
+    let download_options = DownloadOptions {
+        metadata: metadata_config.clone(),
+        ..Default::default()
+    };
 
     // Process each track search
     for (search_query, genre, era) in TRACK_SEARCHES.iter() {
@@ -104,7 +109,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
                 // Download in MP3 format
                 match service
-                    .download_track(&track_id, MP3_FORMAT_ID, &mp3_path, &metadata_config)
+                    .download_track(&track_id, MP3_FORMAT_ID, &mp3_path, &download_options)
                     .await
                 {
                     Ok(_) => {
@@ -125,7 +130,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         &track_id,
                         FLAC_HIRES_FORMAT_ID,
                         &flac_path,
-                        &metadata_config,
+                        &download_options,
                     )
                     .await
                 {
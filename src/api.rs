@@ -36,10 +36,32 @@ pub mod content;
 /// Favorites management module for the Qobuz API.
 ///
 /// This module provides functionality for managing user favorites, including adding,
-/// removing, and retrieving favorite tracks, albums, and artists. It requires user
-/// authentication to access and modify the user's favorites.
+/// removing, and retrieving favorite tracks, albums, and artists. Typed methods accept
+/// ID lists, split them into API-sized batches and report the outcome per ID. It requires
+/// user authentication to access and modify the user's favorites.
 pub mod favorites;
 
+/// Pagination helpers for the Qobuz API.
+///
+/// This module provides utilities for walking paginated endpoints. It turns the
+/// `limit`/`offset` pagination used throughout the Qobuz API into streams that
+/// yield every item and request further pages only as they are consumed.
+pub mod pagination;
+
+/// Purchases module for the Qobuz API.
+///
+/// This module provides functionality for listing the albums and tracks bought by the
+/// authenticated user. Purchased items can be downloaded exactly as bought by using the
+/// download intent when requesting their file URLs.
+pub mod purchases;
+
+/// Streaming report module for the Qobuz API.
+///
+/// This module provides functionality for playback clients to report when the streaming
+/// of a track starts and ends, so that artists are credited for the plays. Events that
+/// cannot be sent while offline are kept in a local queue and flushed later.
+pub mod reporting;
+
 /// HTTP request handling module for the Qobuz API.
 ///
 /// This module contains the core request functions used to communicate with the Qobuz API.
@@ -37,25 +37,76 @@ pub mod albums;
 /// by name, and retrieving an artist's discography.
 pub mod artists;
 
+/// Batch retrieval functionality.
+///
+/// This module provides methods for retrieving many albums, tracks or artists at once,
+/// running a bounded number of requests concurrently while keeping the input order and
+/// reporting failures per ID.
+pub mod batch;
+
 /// Catalog-related API functionality.
 ///
 /// This module provides methods for searching the overall Qobuz catalog. It allows searching
 /// across multiple content types (albums, artists, tracks, etc.) with a single query.
 pub mod catalog;
 
+/// Editorial and discovery API functionality.
+///
+/// This module provides methods for browsing the editorial listings of the Qobuz platform,
+/// such as new releases, press awards, best sellers and featured playlists, optionally
+/// filtered by genre. It also provides navigation of the genre hierarchy.
+pub mod discovery;
+
+/// Catalog identifier lookup functionality.
+///
+/// This module provides methods for finding albums by UPC and tracks by ISRC, keeping only
+/// exact identifier matches among the search candidates, with bulk lookups that run a
+/// bounded number of requests concurrently.
+pub mod identifiers;
+
 /// Label and article-related API functionality.
 ///
 /// This module provides methods for interacting with labels and articles on the Qobuz platform.
-/// It includes functionality for searching articles and retrieving label information.
+/// It includes functionality for searching articles, retrieving label information, and listing,
+/// searching, and walking the album catalog of labels.
 pub mod labels_and_articles;
 
+/// Qobuz link parsing functionality.
+///
+/// This module provides a parser for Qobuz player, open and store links, turning them into
+/// typed references to albums, tracks, artists, playlists and labels, and a method for
+/// fetching the referenced resource.
+pub mod links;
+
 /// Playlist-related API functionality.
 ///
 /// This module provides methods for retrieving and searching playlists on the Qobuz platform.
-/// It includes functionality for getting detailed playlist information and searching for
-/// playlists by name or content.
+/// It includes functionality for getting detailed playlist information, searching for
+/// playlists by name or content, and downloading playlists with an M3U8 playlist file.
 pub mod playlists;
 
+/// Radio track feed functionality.
+///
+/// This module provides radio-style track feeds that walk the similar-artist graph from
+/// a seed artist or album and produce an endless, non-repeating queue of top tracks,
+/// with configurable diversity and explicit-content filtering.
+pub mod radio;
+
+/// Filtered search functionality.
+///
+/// This module provides a search query with client-side filters on the returned albums
+/// and tracks, such as hi-res availability, release years, genre and explicit content.
+/// Filtered searches page through the results until enough matches are found and remove
+/// duplicate editions by UPC or ISRC.
+pub mod search_query;
+
+/// Library synchronization functionality.
+///
+/// This module provides a method for bringing a download library up to date with a list
+/// of albums and playlists, reusing the tracks recorded in the library manifest and
+/// downloading only the missing, corrupt or upgradable ones.
+pub mod sync;
+
 /// Track-related API functionality.
 ///
 /// This module provides methods for retrieving, searching, and downloading tracks on the Qobuz platform.
@@ -1,11 +1,19 @@
-use std::fs::create_dir_all;
+use std::{collections::HashMap, fs::create_dir_all, sync::atomic::AtomicUsize};
+
+use futures_util::{StreamExt, stream::iter};
 
 use crate::{
-    api::service::QobuzApiService,
-    errors::QobuzApiError::{self, ApiErrorResponse, IoError},
-    metadata::MetadataConfig,
-    models::{Album, SearchResult},
-    utils::sanitize_filename,
+    api::{
+        content::batch::DEFAULT_BATCH_CONCURRENCY, requests::AuthPolicy, service::QobuzApiService,
+    },
+    download::{
+        DownloadOptions,
+        outcome::{DownloadOutcome, extension_for_format_id},
+        progress::{AlbumContext, DownloadPhase, ProgressReporter},
+        template::TemplateValues,
+    },
+    errors::QobuzApiError::{self, ApiErrorResponse, InvalidParameterError, IoError},
+    models::{Album, SearchResult, Track},
 };
 
 impl QobuzApiService {
@@ -60,9 +68,51 @@ impl QobuzApiService {
         params.push(("limit".to_string(), limit.unwrap_or(1200).to_string()));
         params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
 
-        let _use_auth = with_auth.unwrap_or(false);
+        self.get_with_auth("/album/get", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Retrieves the editorial description of the specified album, such as its review.
+    ///
+    /// The description is HTML. Use [`html_to_text`](crate::utils::html_to_text) or
+    /// [`html_to_markdown`](crate::utils::html_to_markdown) to display it.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    /// * `with_auth` - Optional boolean to execute request with or without user authentication token.
+    ///   When `None`, defaults to `false` (no authentication).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` - The HTML description of the album
+    /// * `Ok(None)` - If the album has no description
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, utils::html_to_markdown};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = QobuzApiService::new().await?;
+    /// if let Some(description) = service.get_album_description("12345", None).await? {
+    ///     println!("{}", html_to_markdown(&description));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_album_description(
+        &self,
+        album_id: &str,
+        with_auth: Option<bool>,
+    ) -> Result<Option<String>, QobuzApiError> {
+        let album = self
+            .get_album(album_id, with_auth, None, None, None)
+            .await?;
 
-        self.get("/album/get", &params).await
+        Ok(album
+            .description
+            .filter(|description| !description.trim().is_empty()))
     }
 
     /// Searches for albums using the specified query.
@@ -112,16 +162,16 @@ impl QobuzApiService {
             ("offset".to_string(), offset.unwrap_or(0).to_string()),
         ];
 
-        let _use_auth = with_auth.unwrap_or(false);
-
-        self.get("/album/search", &params).await
+        self.get_with_auth("/album/search", &params, AuthPolicy::from(with_auth))
+            .await
     }
 
     /// Downloads an entire album to the specified path.
     ///
     /// This method downloads all tracks of an album to a specified directory, with options for
-    /// different audio quality formats. The method handles track-by-track downloads and includes
-    /// automatic credential refresh if signature errors occur during the download process.
+    /// different audio quality formats. Tracks are downloaded one after another, or several at
+    /// a time as set by [`DownloadOptions::concurrency`]. The method includes automatic
+    /// credential refresh if signature errors occur during the download process.
     ///
     /// # Arguments
     ///
@@ -133,25 +183,35 @@ impl QobuzApiService {
     ///   - "27": FLAC Hi-Res (24-bit/192kHz)
     /// * `path` - The directory path where the album should be saved. The directory will be created
     ///   if it doesn't exist. The path should already include artist/album folder structure.
+    ///   The track files are named after [`DownloadOptions::track_template`].
+    /// * `options` - Download options, including the metadata to embed, the file URL intent and
+    ///   the number of concurrent track downloads.
+    ///   Set the intent to [`FileUrlIntent::Download`](crate::api::content::tracks::FileUrlIntent::Download)
+    ///   to download a purchased album exactly as bought.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - If all tracks in the album are downloaded successfully
-    /// * `Err(QobuzApiError)` - If the API request fails, download fails for any track, or other
-    ///   errors occur during the process
+    /// * `Ok(Vec<DownloadOutcome>)` - If all tracks in the album are downloaded successfully,
+    ///   the outcome of each track download in album order
+    /// * `Err(QobuzApiError)` - If the API request fails, download fails for any track, two
+    ///   tracks would be saved to the same path, or other errors occur during the process
     ///
     /// # Note
     ///
-    /// This method includes automatic retry with credential refresh if signature errors occur.
-    /// Each track is downloaded with progress reporting and metadata embedding.
+    /// A track whose details cannot be retrieved or that fails to download is retried once
+    /// on its own, after the other tracks, with credential refresh if a signature error
+    /// occurred. Every failed track is retried before the error of the first one that
+    /// still fails is returned. Progress is reported to [`DownloadOptions::progress`] with
+    /// the position of each track in the album, and metadata is embedded in each track.
     ///
     /// # Example
     ///
     /// ```
-    /// # use qobuz_api_rust::QobuzApiService;
+    /// # use qobuz_api_rust::{QobuzApiService, download::DownloadOptions};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let service = QobuzApiService::new().await?;
-    /// service.download_album("12345", "6", "./downloads/Artist/Album Title").await?;
+    /// let options = DownloadOptions::default();
+    /// service.download_album("12345", "6", "./downloads/Artist/Album Title", &options).await?;
     /// println!("Album downloaded successfully!");
     /// # Ok(())
     /// # }
@@ -161,117 +221,248 @@ impl QobuzApiService {
         album_id: &str,
         format_id: &str,
         path: &str,
-        config: &MetadataConfig,
-    ) -> Result<(), QobuzApiError> {
+        options: &DownloadOptions,
+    ) -> Result<Vec<DownloadOutcome>, QobuzApiError> {
         let album = self
             .get_album(album_id, None, Some("track_ids"), None, None)
             .await?;
 
-        if let Some(track_ids) = album.track_ids {
-            let total_tracks = track_ids.len();
-            println!();
-            println!("Album contains {} tracks", total_tracks);
-            println!();
-
-            // Create the directory structure as provided in path parameter
-            let album_dir = path;
-            create_dir_all(album_dir).map_err(IoError)?;
-
-            for (index, track_id) in track_ids.iter().enumerate() {
-                let track = self.get_track(&track_id.to_string(), None).await?;
-                let file_extension = match format_id {
-                    "5" => "mp3",
-                    "6" | "7" | "27" => "flac",
-                    _ => "flac", // default to flac
-                };
-
-                // Get track number and title for the filename
-                let track_number = track.track_number.unwrap_or(0);
-                let track_title = track
-                    .title
-                    .as_ref()
-                    .unwrap_or(&format!("Track {}", track_id))
-                    .clone();
-
-                // Create filename following MusicBrainz Picard style: [Titelnr.]. [Titel]
-                let track_filename = format!("{:02}. {}", track_number, track_title);
-                let sanitized_filename = sanitize_filename(&track_filename);
-                let track_path = format!("{}/{}.{}", album_dir, sanitized_filename, file_extension);
-
-                println!(
-                    "Downloading track {}/{}: {} - {}",
-                    index + 1,
-                    total_tracks,
-                    track_number,
-                    track_title
+        let Some(track_ids) = album.track_ids.clone() else {
+            return Ok(Vec::new());
+        };
+
+        let total_tracks = track_ids.len();
+
+        // Create the directory structure as provided in path parameter
+        let album_dir = path;
+        create_dir_all(album_dir).map_err(IoError)?;
+
+        // Fetch the details of all tracks up front, a few at a time
+        let track_ids: Vec<String> = track_ids.iter().map(|id| id.to_string()).collect();
+        let track_id_refs: Vec<&str> = track_ids.iter().map(String::as_str).collect();
+        let tracks = self
+            .get_tracks(&track_id_refs, None, DEFAULT_BATCH_CONCURRENCY)
+            .await;
+
+        // The expected extension, corrected once the delivered format is known
+        let file_extension = extension_for_format_id(format_id);
+
+        // A track whose details could not be retrieved is retried with the failed downloads
+        let mut found_tracks = Vec::with_capacity(total_tracks);
+        let mut lookup_failures = Vec::new();
+        for (index, (track_id, track)) in tracks.into_iter().enumerate() {
+            match track {
+                Ok(track) => found_tracks.push((index, track_id, track)),
+                Err(e) => lookup_failures.push((index, track_id, None, e)),
+            }
+        }
+
+        // Track numbers restart on every disc, so count the tracks of each disc. Without
+        // every track, the counts are left to the track downloads.
+        let mut disc_track_counts: HashMap<i32, i32> = HashMap::new();
+        if lookup_failures.is_empty() {
+            for (_, _, track) in &found_tracks {
+                *disc_track_counts
+                    .entry(track.media_number.unwrap_or(1))
+                    .or_default() += 1;
+            }
+        }
+
+        let mut layout = AlbumLayout {
+            album: &album,
+            format_id,
+            album_dir,
+            file_extension,
+            options,
+            disc_track_counts,
+            paths: HashMap::new(),
+        };
+        let jobs = found_tracks
+            .into_iter()
+            .map(|(index, track_id, track)| layout.job(index, track_id, &track))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let completed_tracks = AtomicUsize::new(0);
+        let album_context = |job: &AlbumTrackJob| AlbumContext {
+            track_position: job.index + 1,
+            track_count: total_tracks,
+            completed_tracks: &completed_tracks,
+            disc_track_count: job.disc_track_count,
+        };
+
+        // Download the tracks, running the configured number of downloads at the same time.
+        // The downloads are created up front to keep the returned future `Send`.
+        let downloads: Vec<_> = jobs
+            .iter()
+            .map(|job| {
+                let download = self.download_track_in_album(
+                    &job.track_id,
+                    format_id,
+                    &job.track_path,
+                    options,
+                    Some(album_context(job)),
                 );
+                async move { (job, download.await) }
+            })
+            .collect();
+
+        let results: Vec<(&AlbumTrackJob, Result<DownloadOutcome, QobuzApiError>)> =
+            iter(downloads)
+                .buffer_unordered(options.concurrency.max(1))
+                .collect()
+                .await;
 
-                // Attempt to download the track, with credential refresh on signature errors
-                match self
-                    .download_track(&track_id.to_string(), format_id, &track_path, config)
+        let mut outcomes = Vec::with_capacity(total_tracks);
+        let mut failed = lookup_failures;
+        for (job, result) in results {
+            match result {
+                Ok(outcome) => outcomes.push((job.index, outcome)),
+                Err(e) => failed.push((job.index, job.track_id.clone(), Some(job), e)),
+            }
+        }
+
+        // Retry every failed track once, on its own
+        failed.sort_by_key(|(index, ..)| *index);
+
+        let mut refresh_attempted = false;
+        let mut refreshed_service = None;
+        let mut retry_errors = Vec::new();
+        for (index, track_id, job, error) in failed {
+            let retried_job;
+            let job = match job {
+                Some(job) => job,
+                None => match refreshed_service
+                    .as_ref()
+                    .unwrap_or(self)
+                    .get_track(&track_id, None)
                     .await
+                    .and_then(|track| layout.job(index, track_id, &track))
                 {
-                    Ok(()) => {
-                        // Success, continue to next track
+                    Ok(job) => {
+                        retried_job = job;
+                        &retried_job
                     }
-
-                    Err(ApiErrorResponse { message, .. })
-                        if message.contains("Invalid Request Signature parameter") =>
-                    {
-                        eprintln!(
-                            "Invalid signature detected during album download, attempting to refresh app credentials..."
-                        );
-
-                        // Refresh credentials and retry the track download
-                        match self.refresh_app_credentials().await {
-                            Ok(new_service) => {
-                                // Use the new service instance to download the track
-                                match new_service
-                                    .download_track(
-                                        &track_id.to_string(),
-                                        format_id,
-                                        &track_path,
-                                        config,
-                                    )
-                                    .await
-                                {
-                                    Ok(()) => {
-                                        // Successfully downloaded with new credentials
-                                    }
-
-                                    Err(e) => {
-                                        // If it still fails, return the error
-                                        return Err(e);
-                                    }
-                                }
-                            }
-
-                            Err(e) => {
-                                eprintln!("Failed to refresh credentials: {}", e);
-                                return Err(ApiErrorResponse {
-                                    code: 400.to_string(),
-                                    message,
-                                    status: "error".to_string(),
-                                });
-                            }
-                        }
+                    Err(e) => {
+                        retry_errors.push(e);
+                        continue;
                     }
+                },
+            };
+
+            let progress = ProgressReporter {
+                observer: options.progress.as_ref(),
+                track_id: &job.track_id,
+                path: &job.track_path,
+                album: Some(album_context(job)),
+            };
+            let mut retry_reason = error.to_string();
 
+            if let ApiErrorResponse { message, .. } = &error
+                && message.contains("Invalid Request Signature parameter")
+                && !refresh_attempted
+            {
+                progress.phase(DownloadPhase::RefreshingCredentials);
+
+                // Refresh credentials once, and use them for the remaining retries
+                refresh_attempted = true;
+                match self.refresh_app_credentials().await {
+                    Ok(new_service) => refreshed_service = Some(new_service),
                     Err(e) => {
-                        // Any other error, return immediately
-                        return Err(e);
+                        retry_reason = format!("{}, failed to refresh credentials: {}", error, e)
                     }
                 }
             }
+            let service = refreshed_service.as_ref().unwrap_or(self);
+
+            progress.phase(DownloadPhase::Retrying(retry_reason));
+
+            match service
+                .download_track_in_album(
+                    &job.track_id,
+                    format_id,
+                    &job.track_path,
+                    options,
+                    Some(album_context(job)),
+                )
+                .await
+            {
+                Ok(outcome) => outcomes.push((job.index, outcome)),
+                Err(e) => retry_errors.push(e),
+            }
+        }
+
+        // Every retry has run, report the first track that still failed
+        if let Some(error) = retry_errors.into_iter().next() {
+            return Err(error);
+        }
+
+        outcomes.sort_by_key(|(index, _)| *index);
+        Ok(outcomes.into_iter().map(|(_, outcome)| outcome).collect())
+    }
+}
+
+/// How the tracks of an album download are laid out in the album folder.
+struct AlbumLayout<'a> {
+    album: &'a Album,
+    format_id: &'a str,
+    album_dir: &'a str,
+    file_extension: &'a str,
+    options: &'a DownloadOptions,
+    /// Number of tracks on each disc, empty if unknown
+    disc_track_counts: HashMap<i32, i32>,
+    /// The track ID saved to each path, by lowercase path
+    paths: HashMap<String, String>,
+}
 
-            println!();
-            println!(
-                "Album download completed: {}/{} tracks downloaded",
-                total_tracks, total_tracks
-            );
-            println!();
+impl AlbumLayout<'_> {
+    /// Names the file of a track after the track template, relative to the album folder.
+    ///
+    /// Paths are compared case-insensitively, as some file systems do, and a track that
+    /// would be saved to the path of another track is rejected.
+    fn job(
+        &mut self,
+        index: usize,
+        track_id: String,
+        track: &Track,
+    ) -> Result<AlbumTrackJob, QobuzApiError> {
+        let values = TemplateValues::for_track(track, self.album, self.format_id);
+        let track_filename = self.options.track_template.render(&values);
+        let track_path = format!(
+            "{}/{}.{}",
+            self.album_dir, track_filename, self.file_extension
+        );
+
+        if let Some(other_id) = self
+            .paths
+            .insert(track_path.to_lowercase(), track_id.clone())
+        {
+            return Err(InvalidParameterError {
+                message: format!(
+                    "Tracks {} and {} would both be saved to {}, use a track template \
+                     telling them apart, such as a disc layout",
+                    other_id, track_id, track_path
+                ),
+            });
         }
 
-        Ok(())
+        Ok(AlbumTrackJob {
+            index,
+            track_id,
+            track_path,
+            disc_track_count: self
+                .disc_track_counts
+                .get(&track.media_number.unwrap_or(1))
+                .copied(),
+        })
     }
 }
+
+/// A track of an album download, with the file it is saved to.
+struct AlbumTrackJob {
+    /// Position of the track in the album, starting at 0
+    index: usize,
+    track_id: String,
+    track_path: String,
+    /// Number of tracks on the disc of the track
+    disc_track_count: Option<i32>,
+}
@@ -1,7 +1,7 @@
 use crate::{
-    api::service::QobuzApiService,
+    api::{requests::AuthPolicy, service::QobuzApiService},
     errors::QobuzApiError::{self},
-    models::{Artist, ReleasesList, SearchResult},
+    models::{Artist, Biography, ReleasesList, SearchResult, SimilarArtists, Track},
 };
 
 /// Parameters for the artist release list API.
@@ -118,9 +118,8 @@ impl QobuzApiService {
         params.push(("limit".to_string(), limit.unwrap_or(50).to_string()));
         params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
 
-        let _use_auth = with_auth.unwrap_or(false);
-
-        self.get("/artist/get", &params).await
+        self.get_with_auth("/artist/get", &params, AuthPolicy::from(with_auth))
+            .await
     }
 
     /// Retrieves a list of releases for the specified artist.
@@ -182,9 +181,12 @@ impl QobuzApiService {
         query_params.push(("limit".to_string(), params.limit.unwrap_or(50).to_string()));
         query_params.push(("offset".to_string(), params.offset.unwrap_or(0).to_string()));
 
-        let _use_auth = params.with_auth.unwrap_or(false);
-
-        self.get("/artist/getReleasesList", &query_params).await
+        self.get_with_auth(
+            "/artist/getReleasesList",
+            &query_params,
+            AuthPolicy::from(params.with_auth),
+        )
+        .await
     }
 
     /// Searches for artists matching the specified query.
@@ -230,8 +232,140 @@ impl QobuzApiService {
             ("offset".to_string(), offset.unwrap_or(0).to_string()),
         ];
 
-        let _use_auth = with_auth.unwrap_or(false);
+        self.get_with_auth("/artist/search", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Retrieves artists similar to the specified artist.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist to find similar artists for
+    /// * `limit` - Maximum number of similar artists to return (optional, defaults to 50)
+    /// * `offset` - Offset for pagination (optional, defaults to 0)
+    /// * `with_auth` - Whether to execute the request with user authentication (optional, defaults to false)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(SimilarArtists)` containing a page of similar artists if successful, or
+    /// `Err(QobuzApiError)` if the API request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::QobuzApiService;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = QobuzApiService::new().await?;
+    /// let similar = service.get_similar_artists("12345", Some(10), None, None).await?;
+    /// for artist in similar.artists.and_then(|a| a.items).unwrap_or_default() {
+    ///     println!("{:?}", artist.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_similar_artists(
+        &self,
+        artist_id: &str,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        with_auth: Option<bool>,
+    ) -> Result<SimilarArtists, QobuzApiError> {
+        let params = vec![
+            ("artist_id".to_string(), artist_id.to_string()),
+            ("limit".to_string(), limit.unwrap_or(50).to_string()),
+            ("offset".to_string(), offset.unwrap_or(0).to_string()),
+        ];
+
+        self.get_with_auth(
+            "/artist/getSimilarArtists",
+            &params,
+            AuthPolicy::from(with_auth),
+        )
+        .await
+    }
+
+    /// Retrieves the biography of the specified artist.
+    ///
+    /// The content and summary of the biography are HTML. Use
+    /// [`html_to_text`](crate::utils::html_to_text) or
+    /// [`html_to_markdown`](crate::utils::html_to_markdown) to display them.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `with_auth` - Whether to use authentication for this request (optional, defaults to false)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some(Biography))` if the artist has a biography, `Ok(None)` if it has none,
+    /// or `Err(QobuzApiError)` if the API request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, utils::html_to_text};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = QobuzApiService::new().await?;
+    /// if let Some(biography) = service.get_artist_biography("12345", None).await?
+    ///     && let Some(content) = biography.content
+    /// {
+    ///     println!("{}", html_to_text(&content));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_artist_biography(
+        &self,
+        artist_id: &str,
+        with_auth: Option<bool>,
+    ) -> Result<Option<Biography>, QobuzApiError> {
+        let artist = self
+            .get_artist(artist_id, with_auth, None, None, None, None)
+            .await?;
+
+        Ok(artist.biography)
+    }
+
+    /// Retrieves the top tracks of the specified artist.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist whose top tracks to fetch
+    /// * `limit` - Maximum number of tracks to return (optional, defaults to 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vec<Track>)` containing the artist's top tracks, most popular first, if
+    /// successful, or `Err(QobuzApiError)` if the API request fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::QobuzApiService;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = QobuzApiService::new().await?;
+    /// let tracks = service.get_artist_top_tracks("12345", Some(5)).await?;
+    /// for track in tracks {
+    ///     println!("{:?}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_artist_top_tracks(
+        &self,
+        artist_id: &str,
+        limit: Option<i32>,
+    ) -> Result<Vec<Track>, QobuzApiError> {
+        let artist = self
+            .get_artist(artist_id, None, Some("tracks"), None, limit, None)
+            .await?;
 
-        self.get("/artist/search", &params).await
+        Ok(artist
+            .tracks
+            .and_then(|tracks| tracks.items)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|track| *track)
+            .collect())
     }
 }
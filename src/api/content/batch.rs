@@ -0,0 +1,172 @@
+use std::future::Future;
+
+use futures_util::{StreamExt, stream::iter};
+
+use crate::{
+    api::service::QobuzApiService,
+    errors::QobuzApiError,
+    models::{Album, Artist, Track},
+};
+
+/// The number of requests a batch runs at the same time when no other limit is given.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// Runs `fetch` for every ID, with at most `concurrency` requests in flight.
+///
+/// The results are returned as `(id, result)` pairs in the order of `ids`, and a failed
+/// request does not affect the others. A concurrency of 0 is treated as 1.
+pub(crate) async fn fetch_all<'a, T, F, Fut>(
+    ids: &'a [&'a str],
+    concurrency: usize,
+    fetch: F,
+) -> Vec<(String, Result<T, QobuzApiError>)>
+where
+    F: Fn(&'a str) -> Fut,
+    Fut: Future<Output = Result<T, QobuzApiError>>,
+{
+    // Create the requests up front, so that the stream does not hold a closure borrowing
+    // the IDs, which would keep the returned future from being `Send`
+    let requests: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            let request = fetch(id);
+            async move { (id.to_string(), request.await) }
+        })
+        .collect();
+
+    iter(requests).buffered(concurrency.max(1)).collect().await
+}
+
+impl QobuzApiService {
+    /// Retrieves several albums, running a bounded number of requests at a time.
+    ///
+    /// Each album is fetched with [`get_album`](Self::get_album), so the requests go
+    /// through the same request handling as single lookups.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_ids` - The unique identifiers of the albums to retrieve
+    /// * `with_auth` - Optional boolean to execute the requests with or without user
+    ///   authentication token. When `None`, defaults to `false` (no authentication).
+    /// * `concurrency` - The maximum number of requests running at the same time
+    ///   (see [`DEFAULT_BATCH_CONCURRENCY`])
+    ///
+    /// # Returns
+    ///
+    /// An `(album_id, result)` pair for each ID, in the order of `album_ids`. A failed
+    /// request does not abort the batch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::batch::DEFAULT_BATCH_CONCURRENCY;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let ids = ["0886443927087", "0060254735180"];
+    ///
+    /// for (id, result) in service.get_albums(&ids, None, DEFAULT_BATCH_CONCURRENCY).await {
+    ///     match result {
+    ///         Ok(album) => println!("{}: {:?}", id, album.title),
+    ///         Err(e) => println!("{}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_albums(
+        &self,
+        album_ids: &[&str],
+        with_auth: Option<bool>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Album, QobuzApiError>)> {
+        fetch_all(album_ids, concurrency, |id| {
+            self.get_album(id, with_auth, None, None, None)
+        })
+        .await
+    }
+
+    /// Retrieves several tracks, running a bounded number of requests at a time.
+    ///
+    /// Each track is fetched with [`get_track`](Self::get_track), so the requests go
+    /// through the same request handling as single lookups.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_ids` - The unique identifiers of the tracks to retrieve
+    /// * `with_auth` - Optional boolean to execute the requests with or without user
+    ///   authentication token. When `None`, defaults to `false` (no authentication).
+    /// * `concurrency` - The maximum number of requests running at the same time
+    ///   (see [`DEFAULT_BATCH_CONCURRENCY`])
+    ///
+    /// # Returns
+    ///
+    /// A `(track_id, result)` pair for each ID, in the order of `track_ids`. A failed
+    /// request does not abort the batch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let results = service.get_tracks(&["40128300", "40128301"], None, 4).await;
+    /// let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+    /// println!("{} of {} tracks failed", failed, results.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tracks(
+        &self,
+        track_ids: &[&str],
+        with_auth: Option<bool>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Track, QobuzApiError>)> {
+        fetch_all(track_ids, concurrency, |id| self.get_track(id, with_auth)).await
+    }
+
+    /// Retrieves several artists, running a bounded number of requests at a time.
+    ///
+    /// Each artist is fetched with [`get_artist`](Self::get_artist), so the requests go
+    /// through the same request handling as single lookups.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_ids` - The unique identifiers of the artists to retrieve
+    /// * `with_auth` - Optional boolean to execute the requests with or without user
+    ///   authentication token. When `None`, defaults to `false` (no authentication).
+    /// * `concurrency` - The maximum number of requests running at the same time
+    ///   (see [`DEFAULT_BATCH_CONCURRENCY`])
+    ///
+    /// # Returns
+    ///
+    /// An `(artist_id, result)` pair for each ID, in the order of `artist_ids`. A failed
+    /// request does not abort the batch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let artists: Vec<_> = service
+    ///     .get_artists(&["36819", "38895"], None, 4)
+    ///     .await
+    ///     .into_iter()
+    ///     .filter_map(|(_, result)| result.ok())
+    ///     .collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_artists(
+        &self,
+        artist_ids: &[&str],
+        with_auth: Option<bool>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Artist, QobuzApiError>)> {
+        fetch_all(artist_ids, concurrency, |id| {
+            self.get_artist(id, with_auth, None, None, None, None)
+        })
+        .await
+    }
+}
@@ -1,9 +1,296 @@
+use std::collections::{HashSet, VecDeque};
+
+use {
+    serde::{Deserialize, Serialize},
+    serde_json::{Value, from_value},
+};
+
 use crate::{
-    api::service::QobuzApiService,
+    api::{requests::AuthPolicy, service::QobuzApiService},
     errors::QobuzApiError::{self},
-    models::SearchResult,
+    models::{
+        Album, Article, Artist, ItemSearchResult, MostPopular, Playlist, SearchResult, Track,
+    },
 };
 
+/// The kinds of results returned by a catalog search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatalogHitKind {
+    /// Artist results
+    Artists,
+    /// Album results
+    Albums,
+    /// Track results
+    Tracks,
+    /// Playlist results
+    Playlists,
+    /// Article results
+    Articles,
+}
+
+impl CatalogHitKind {
+    /// Every kind of result, in the order used to rank results of equal relevance.
+    pub const ALL: [Self; 5] = [
+        Self::Artists,
+        Self::Albums,
+        Self::Tracks,
+        Self::Playlists,
+        Self::Articles,
+    ];
+
+    /// Returns the string representation used by the `type` parameter of the search API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Artists => "artists",
+            Self::Albums => "albums",
+            Self::Tracks => "tracks",
+            Self::Playlists => "playlists",
+            Self::Articles => "articles",
+        }
+    }
+
+    /// Parses the type of a most popular item, in singular or plural form.
+    fn from_type(type_field: &str) -> Option<Self> {
+        match type_field {
+            "artist" | "artists" => Some(Self::Artists),
+            "album" | "albums" => Some(Self::Albums),
+            "track" | "tracks" => Some(Self::Tracks),
+            "playlist" | "playlists" => Some(Self::Playlists),
+            "article" | "articles" => Some(Self::Articles),
+            _ => None,
+        }
+    }
+}
+
+/// A single result of a catalog search, of any kind.
+#[derive(Debug, Clone)]
+pub enum CatalogHit {
+    /// An artist result
+    Artist(Box<Artist>),
+    /// An album result
+    Album(Box<Album>),
+    /// A track result
+    Track(Box<Track>),
+    /// A playlist result
+    Playlist(Box<Playlist>),
+    /// An article result
+    Article(Box<Article>),
+}
+
+impl CatalogHit {
+    /// Returns the kind of the result.
+    pub fn kind(&self) -> CatalogHitKind {
+        match self {
+            Self::Artist(_) => CatalogHitKind::Artists,
+            Self::Album(_) => CatalogHitKind::Albums,
+            Self::Track(_) => CatalogHitKind::Tracks,
+            Self::Playlist(_) => CatalogHitKind::Playlists,
+            Self::Article(_) => CatalogHitKind::Articles,
+        }
+    }
+
+    /// Returns the ID of the result, if known.
+    pub fn id(&self) -> Option<String> {
+        match self {
+            Self::Artist(artist) => artist.id.map(|id| id.to_string()),
+            Self::Album(album) => album.id.clone(),
+            Self::Track(track) => track.id.map(|id| id.to_string()),
+            Self::Playlist(playlist) => playlist.id.map(|id| id.to_string()),
+            Self::Article(article) => article.id.clone(),
+        }
+    }
+
+    /// Reads a most popular item as the result indicated by its type.
+    ///
+    /// Returns `None` if the type is unknown or the content does not match it.
+    pub fn from_most_popular(item: &MostPopular) -> Option<Self> {
+        let kind = item
+            .type_field
+            .as_deref()
+            .or(item.content.type_field.as_deref())
+            .and_then(CatalogHitKind::from_type)?;
+        let content = Value::Object(item.content.fields.clone());
+
+        match kind {
+            CatalogHitKind::Artists => from_value(content).ok().map(Self::Artist),
+            CatalogHitKind::Albums => from_value(content).ok().map(Self::Album),
+            CatalogHitKind::Tracks => from_value(content).ok().map(Self::Track),
+            CatalogHitKind::Playlists => from_value(content).ok().map(Self::Playlist),
+            CatalogHitKind::Articles => from_value(content).ok().map(Self::Article),
+        }
+    }
+}
+
+/// A token for requesting the next page of one kind of catalog search results.
+///
+/// Continuations can be serialized, for example to hand them to a UI and receive them
+/// back when the user scrolls further.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogContinuation {
+    /// The search query
+    pub query: String,
+    /// The kind of results to continue
+    pub kind: CatalogHitKind,
+    /// The offset of the next page
+    pub offset: i32,
+    /// The number of results per page
+    pub limit: i32,
+}
+
+/// A page of catalog search results of all kinds, merged into a single ranked list.
+///
+/// The most popular results reported by the API come first, in the order given by the
+/// API. The remaining results follow, interleaved by their rank within their kind, so
+/// that the best result of every kind comes before the second best of any kind. A result
+/// appears only once.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::{
+///     api::content::catalog::{CatalogHit, CatalogHitKind, CatalogSearchPage},
+///     models::SearchResult,
+/// };
+/// use serde_json::{from_value, json};
+///
+/// let result: SearchResult = from_value(json!({
+///     "most_popular": { "items": [
+///         { "type": "tracks", "content": { "id": 3, "title": "Karma Police" } }
+///     ] },
+///     "albums": { "items": [{ "id": "a1" }, { "id": "a2" }], "offset": 0, "total": 40 },
+///     "tracks": { "items": [{ "id": 3 }, { "id": 4 }], "offset": 0, "total": 2 },
+/// }))
+/// .unwrap();
+///
+/// let page = CatalogSearchPage::from_search_result("ok computer", result, 2);
+/// let ids: Vec<_> = page.hits.iter().filter_map(CatalogHit::id).collect();
+///
+/// // The most popular track first, then the rest by rank, without the duplicate track
+/// assert_eq!(ids, ["3", "a1", "a2", "4"]);
+///
+/// // Only albums have more results
+/// let next = page.continuation(CatalogHitKind::Albums).unwrap();
+/// assert_eq!(next.offset, 2);
+/// assert!(page.continuation(CatalogHitKind::Tracks).is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CatalogSearchPage {
+    /// The results, most relevant first
+    pub hits: Vec<CatalogHit>,
+    /// A continuation for every kind of result that has more results
+    pub continuations: Vec<CatalogContinuation>,
+}
+
+impl CatalogSearchPage {
+    /// Merges the results of a catalog search into a ranked page.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query, recorded in the continuations
+    /// * `result` - The search result to merge
+    /// * `limit` - The number of results per kind that was requested
+    pub fn from_search_result(query: &str, result: SearchResult, limit: i32) -> Self {
+        Self::build(query, result, limit, &CatalogHitKind::ALL, true)
+    }
+
+    /// Returns the continuation for a kind of result, if it has more results.
+    pub fn continuation(&self, kind: CatalogHitKind) -> Option<&CatalogContinuation> {
+        self.continuations.iter().find(|c| c.kind == kind)
+    }
+
+    fn build(
+        query: &str,
+        mut result: SearchResult,
+        limit: i32,
+        kinds: &[CatalogHitKind],
+        use_most_popular: bool,
+    ) -> Self {
+        let mut page = Self::default();
+        let mut ranked = Vec::new();
+
+        for kind in kinds {
+            let (hits, next_offset) = match kind {
+                CatalogHitKind::Artists => take_page(result.artists.take(), CatalogHit::Artist),
+                CatalogHitKind::Albums => take_page(result.albums.take(), CatalogHit::Album),
+                CatalogHitKind::Tracks => take_page(result.tracks.take(), CatalogHit::Track),
+                CatalogHitKind::Playlists => {
+                    take_page(result.playlists.take(), CatalogHit::Playlist)
+                }
+                CatalogHitKind::Articles => take_page(result.articles.take(), CatalogHit::Article),
+            };
+
+            if let Some(offset) = next_offset {
+                page.continuations.push(CatalogContinuation {
+                    query: query.to_string(),
+                    kind: *kind,
+                    offset,
+                    limit,
+                });
+            }
+            ranked.push(hits);
+        }
+
+        let mut seen = HashSet::new();
+        let mut push = |hit: CatalogHit| {
+            // Results without an ID cannot be told apart, so they are always kept
+            let is_new = match hit.id() {
+                Some(id) => seen.insert((hit.kind(), id)),
+                None => true,
+            };
+            if is_new {
+                page.hits.push(hit);
+            }
+        };
+
+        if use_most_popular {
+            let most_popular = result.most_popular.and_then(|m| m.items);
+            for item in most_popular.unwrap_or_default() {
+                if let Some(hit) = CatalogHit::from_most_popular(&item) {
+                    push(hit);
+                }
+            }
+        }
+
+        // Interleave the remaining results by their rank within their kind
+        while ranked.iter().any(|hits| !hits.is_empty()) {
+            for hits in &mut ranked {
+                if let Some(hit) = hits.pop_front() {
+                    push(hit);
+                }
+            }
+        }
+
+        page
+    }
+}
+
+/// Wraps the items of a page of results, and returns the offset of the next page if
+/// there are more results.
+fn take_page<T>(
+    page: Option<ItemSearchResult<T>>,
+    wrap: fn(Box<T>) -> CatalogHit,
+) -> (VecDeque<CatalogHit>, Option<i32>) {
+    let Some(page) = page else {
+        return (VecDeque::new(), None);
+    };
+
+    let items = page.items.unwrap_or_default();
+    let next_offset = page.offset.unwrap_or(0) + items.len() as i32;
+
+    // Prefer the explicit flag, then the total count
+    let has_more = !items.is_empty()
+        && match (page.has_more, page.total) {
+            (Some(has_more), _) => has_more,
+            (None, Some(total)) => next_offset < total,
+            (None, None) => false,
+        };
+
+    let hits = items.into_iter().map(|item| wrap(Box::new(item))).collect();
+
+    (hits, has_more.then_some(next_offset))
+}
+
 /// Provides functionality for interacting with the Qobuz catalog API.
 ///
 /// This module contains methods for searching the Qobuz music catalog, allowing
@@ -26,9 +313,9 @@ impl QobuzApiService {
     /// * `type_param` - Optional parameter to limit results to a specific content type.
     ///   Valid values include "albums", "artists", "tracks", "playlists", "labels", etc.
     ///   If not specified, results from all content types will be returned.
-    /// * `with_auth` - Whether to execute the search with the user's authentication token.
-    ///   This parameter is currently unused in the implementation but is reserved for
-    ///   future use to enable authenticated searches that may return personalized results.
+    /// * `with_auth` - Whether to execute the search with the user's authentication token,
+    ///   which may return personalized results. When `None`, defaults to `false`
+    ///   (no authentication).
     ///
     /// # Examples
     ///
@@ -86,8 +373,100 @@ impl QobuzApiService {
             params.push(("type".to_string(), type_val.to_string()));
         }
 
-        let _use_auth = with_auth.unwrap_or(false);
+        self.get_with_auth("/catalog/search", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Searches the catalog and merges the results of all kinds into a ranked list.
+    ///
+    /// This is a single request returning one result type, suitable for search-as-you-type.
+    /// See [`CatalogSearchPage`] for how the results are ranked.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query string
+    /// * `limit` - The maximum number of results per kind. Defaults to 50 if not specified.
+    /// * `with_auth` - Whether to execute the search with the user's authentication token.
+    ///   When `None`, defaults to `false` (no authentication).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CatalogSearchPage)` - The ranked results and a continuation for every kind
+    ///   with more results
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::catalog::{CatalogHit, CatalogHitKind};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let page = service.search_catalog_hits("radiohead", Some(5), None).await?;
+    ///
+    /// for hit in &page.hits {
+    ///     match hit {
+    ///         CatalogHit::Artist(artist) => println!("Artist: {:?}", artist.name),
+    ///         CatalogHit::Album(album) => println!("Album: {:?}", album.title),
+    ///         other => println!("{}: {:?}", other.kind().as_str(), other.id()),
+    ///     }
+    /// }
+    ///
+    /// // Load more albums
+    /// if let Some(next) = page.continuation(CatalogHitKind::Albums) {
+    ///     let more_albums = service.continue_catalog_search(next, None).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_catalog_hits(
+        &self,
+        query: &str,
+        limit: Option<i32>,
+        with_auth: Option<bool>,
+    ) -> Result<CatalogSearchPage, QobuzApiError> {
+        let limit = limit.unwrap_or(50);
+        let result = self
+            .search_catalog(query, Some(limit), None, None, with_auth)
+            .await?;
+
+        Ok(CatalogSearchPage::from_search_result(query, result, limit))
+    }
+
+    /// Retrieves the next page of one kind of catalog search results.
+    ///
+    /// # Arguments
+    ///
+    /// * `continuation` - The continuation of the kind of results to load
+    /// * `with_auth` - Whether to execute the search with the user's authentication token.
+    ///   When `None`, defaults to `false` (no authentication).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CatalogSearchPage)` - The next results of the kind, and a continuation if
+    ///   there are more
+    /// * `Err(QobuzApiError)` - If the API request fails
+    pub async fn continue_catalog_search(
+        &self,
+        continuation: &CatalogContinuation,
+        with_auth: Option<bool>,
+    ) -> Result<CatalogSearchPage, QobuzApiError> {
+        let result = self
+            .search_catalog(
+                &continuation.query,
+                Some(continuation.limit),
+                Some(continuation.offset),
+                Some(continuation.kind.as_str()),
+                with_auth,
+            )
+            .await?;
 
-        self.get("/catalog/search", &params).await
+        Ok(CatalogSearchPage::build(
+            &continuation.query,
+            result,
+            continuation.limit,
+            &[continuation.kind],
+            false,
+        ))
     }
 }
@@ -0,0 +1,437 @@
+use futures_util::Stream;
+
+use crate::{
+    api::{pagination::paginate, requests::AuthPolicy, service::QobuzApiService},
+    errors::QobuzApiError::{self},
+    models::{Album, FeaturedAlbums, FeaturedPlaylists, Genre, GenreList, Playlist},
+};
+
+/// Editorial album listings available through the featured albums API.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::discovery::FeaturedAlbumType;
+///
+/// assert_eq!(FeaturedAlbumType::PressAwards.as_str(), "press-awards");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeaturedAlbumType {
+    /// Albums released recently
+    NewReleases,
+    /// The full list of recent releases, including less prominent ones
+    NewReleasesFull,
+    /// Albums that received press awards
+    PressAwards,
+    /// Best selling albums
+    BestSellers,
+    /// Albums picked by the Qobuz editors
+    EditorPicks,
+    /// Most streamed albums
+    MostStreamed,
+    /// Albums featured in the Qobuz "ideal discography"
+    IdealDiscography,
+    /// Albums awarded a "Qobuzissime"
+    Qobuzissims,
+}
+
+impl FeaturedAlbumType {
+    /// Returns the value of the `type` parameter for this listing.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NewReleases => "new-releases",
+            Self::NewReleasesFull => "new-releases-full",
+            Self::PressAwards => "press-awards",
+            Self::BestSellers => "best-sellers",
+            Self::EditorPicks => "editor-picks",
+            Self::MostStreamed => "most-streamed",
+            Self::IdealDiscography => "ideal-discography",
+            Self::Qobuzissims => "qobuzissims",
+        }
+    }
+}
+
+/// Editorial playlist listings available through the featured playlists API.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::discovery::FeaturedPlaylistType;
+///
+/// assert_eq!(FeaturedPlaylistType::EditorPicks.as_str(), "editor-picks");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeaturedPlaylistType {
+    /// Playlists picked by the Qobuz editors
+    EditorPicks,
+    /// Most recently created public playlists
+    LastCreated,
+}
+
+impl FeaturedPlaylistType {
+    /// Returns the value of the `type` parameter for this listing.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EditorPicks => "editor-picks",
+            Self::LastCreated => "last-created",
+        }
+    }
+}
+
+/// Parameters for the featured album and playlist listing APIs.
+///
+/// This struct contains the genre filter, pagination, and authentication options shared
+/// by the editorial listings.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::discovery::FeaturedListParams;
+///
+/// // Only list jazz and classical releases
+/// let params = FeaturedListParams {
+///     genre_ids: vec![80, 10],
+///     limit: Some(20),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct FeaturedListParams {
+    /// Whether to execute the request with authentication (user_auth_token)
+    ///
+    /// When `Some(true)`, the request will be made with the authenticated user's token.
+    /// When `Some(false)` or `None`, the request will be made without authentication.
+    pub with_auth: Option<bool>,
+    /// IDs of the genres to restrict the listing to
+    ///
+    /// If empty, items of all genres are included.
+    pub genre_ids: Vec<i32>,
+    /// The maximum number of items to return
+    ///
+    /// When streaming, this is the page size used for each request.
+    /// If `None`, defaults to 50 items.
+    pub limit: Option<i32>,
+    /// The offset for pagination
+    ///
+    /// Use this to skip a certain number of items from the beginning of the results.
+    /// If `None`, defaults to 0 (no offset).
+    pub offset: Option<i32>,
+}
+
+impl FeaturedListParams {
+    /// Builds the query parameters shared by the featured listings.
+    fn to_query_params(&self, type_value: &str, genre_key: &str) -> Vec<(String, String)> {
+        let mut params = vec![("type".to_string(), type_value.to_string())];
+
+        if !self.genre_ids.is_empty() {
+            let genre_ids: Vec<String> = self.genre_ids.iter().map(|id| id.to_string()).collect();
+            params.push((genre_key.to_string(), genre_ids.join(",")));
+        }
+
+        params.push(("limit".to_string(), self.limit.unwrap_or(50).to_string()));
+        params.push(("offset".to_string(), self.offset.unwrap_or(0).to_string()));
+
+        params
+    }
+}
+
+impl QobuzApiService {
+    /// Retrieves a page of an editorial album listing.
+    ///
+    /// # Arguments
+    ///
+    /// * `listing` - The editorial listing to retrieve, such as new releases or press awards
+    /// * `params` - Genre filter, pagination, and authentication options
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FeaturedAlbums)` - A page of the listed albums
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::discovery::{FeaturedAlbumType, FeaturedListParams};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let featured = service
+    ///     .get_featured_albums(FeaturedAlbumType::NewReleases, FeaturedListParams::default())
+    ///     .await?;
+    /// for album in featured.albums.and_then(|a| a.items).unwrap_or_default() {
+    ///     println!("{:?}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_featured_albums(
+        &self,
+        listing: FeaturedAlbumType,
+        params: FeaturedListParams,
+    ) -> Result<FeaturedAlbums, QobuzApiError> {
+        let query_params = params.to_query_params(listing.as_str(), "genre_id");
+
+        self.get_with_auth(
+            "/album/getFeatured",
+            &query_params,
+            AuthPolicy::from(params.with_auth),
+        )
+        .await
+    }
+
+    /// Streams every album of an editorial album listing.
+    ///
+    /// Further pages are requested from the API only as the stream is consumed. The
+    /// `limit` of `params` is used as the page size, and `offset` as the position of
+    /// the first album to yield.
+    ///
+    /// # Arguments
+    ///
+    /// * `listing` - The editorial listing to stream
+    /// * `params` - Genre filter, pagination, and authentication options
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding each listed `Album`. If a page request fails, the error is
+    /// yielded and the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::discovery::{FeaturedAlbumType, FeaturedListParams};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let awarded: Vec<_> = service
+    ///     .stream_featured_albums(FeaturedAlbumType::PressAwards, FeaturedListParams::default())
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_featured_albums(
+        &self,
+        listing: FeaturedAlbumType,
+        params: FeaturedListParams,
+    ) -> impl Stream<Item = Result<Album, QobuzApiError>> + '_ {
+        let start_offset = params.offset.unwrap_or(0);
+        let page_size = params.limit.unwrap_or(50);
+
+        paginate(start_offset, page_size, move |limit, offset| {
+            let page_params = FeaturedListParams {
+                limit: Some(limit),
+                offset: Some(offset),
+                ..params.clone()
+            };
+
+            async move {
+                let featured = self.get_featured_albums(listing, page_params).await?;
+                Ok(featured.albums.unwrap_or_default())
+            }
+        })
+    }
+
+    /// Retrieves a page of an editorial playlist listing.
+    ///
+    /// # Arguments
+    ///
+    /// * `listing` - The editorial listing to retrieve, such as editor picks
+    /// * `params` - Genre filter, pagination, and authentication options
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FeaturedPlaylists)` - A page of the listed playlists
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::discovery::{FeaturedListParams, FeaturedPlaylistType};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let featured = service
+    ///     .get_featured_playlists(FeaturedPlaylistType::EditorPicks, FeaturedListParams::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_featured_playlists(
+        &self,
+        listing: FeaturedPlaylistType,
+        params: FeaturedListParams,
+    ) -> Result<FeaturedPlaylists, QobuzApiError> {
+        let query_params = params.to_query_params(listing.as_str(), "genre_ids");
+
+        self.get_with_auth(
+            "/playlist/getFeatured",
+            &query_params,
+            AuthPolicy::from(params.with_auth),
+        )
+        .await
+    }
+
+    /// Streams every playlist of an editorial playlist listing.
+    ///
+    /// Further pages are requested from the API only as the stream is consumed. The
+    /// `limit` of `params` is used as the page size, and `offset` as the position of
+    /// the first playlist to yield.
+    ///
+    /// # Arguments
+    ///
+    /// * `listing` - The editorial listing to stream
+    /// * `params` - Genre filter, pagination, and authentication options
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding each listed `Playlist`. If a page request fails, the error is
+    /// yielded and the stream ends.
+    pub fn stream_featured_playlists(
+        &self,
+        listing: FeaturedPlaylistType,
+        params: FeaturedListParams,
+    ) -> impl Stream<Item = Result<Playlist, QobuzApiError>> + '_ {
+        let start_offset = params.offset.unwrap_or(0);
+        let page_size = params.limit.unwrap_or(50);
+
+        paginate(start_offset, page_size, move |limit, offset| {
+            let page_params = FeaturedListParams {
+                limit: Some(limit),
+                offset: Some(offset),
+                ..params.clone()
+            };
+
+            async move {
+                let featured = self.get_featured_playlists(listing, page_params).await?;
+                Ok(featured.playlists.unwrap_or_default())
+            }
+        })
+    }
+
+    /// Lists one level of the genre hierarchy.
+    ///
+    /// Without a `parent_id`, the top-level genres are returned. With a `parent_id`,
+    /// the direct children of that genre are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_id` - Optional ID of the genre whose children to list
+    /// * `limit` - Maximum number of genres to return (optional, defaults to 50)
+    /// * `offset` - Offset of the first genre to return (optional, defaults to 0)
+    /// * `with_auth` - Whether to execute the request with user authentication (optional, defaults to false)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GenreList)` - A page of genres, along with the parent genre if one was given
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let top_level = service.list_genres(None, None, None, None).await?;
+    /// for genre in top_level.genres.and_then(|g| g.items).unwrap_or_default() {
+    ///     println!("{:?}: {:?}", genre.id, genre.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_genres(
+        &self,
+        parent_id: Option<i32>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        with_auth: Option<bool>,
+    ) -> Result<GenreList, QobuzApiError> {
+        let mut params = Vec::new();
+
+        if let Some(parent_id_val) = parent_id {
+            params.push(("parent_id".to_string(), parent_id_val.to_string()));
+        }
+
+        params.push(("limit".to_string(), limit.unwrap_or(50).to_string()));
+        params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
+
+        self.get_with_auth("/genre/list", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Streams every genre of one level of the genre hierarchy.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_id` - Optional ID of the genre whose children to stream. If `None`,
+    ///   the top-level genres are streamed.
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding each `Genre`. If a page request fails, the error is yielded
+    /// and the stream ends.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// // Children of "Jazz"
+    /// let subgenres: Vec<_> = service.stream_genres(Some(80)).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_genres(
+        &self,
+        parent_id: Option<i32>,
+    ) -> impl Stream<Item = Result<Genre, QobuzApiError>> + '_ {
+        paginate(0, 50, move |limit, offset| async move {
+            let genre_list = self
+                .list_genres(parent_id, Some(limit), Some(offset), None)
+                .await?;
+            Ok(genre_list.genres.unwrap_or_default())
+        })
+    }
+
+    /// Retrieves a single genre using its unique ID.
+    ///
+    /// The returned genre's `path` contains the IDs of its ancestors, which can be used
+    /// to navigate up the hierarchy, while [`list_genres`](Self::list_genres) with the
+    /// genre as `parent_id` navigates down.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre_id` - The unique identifier of the genre to retrieve
+    /// * `with_auth` - Whether to execute the request with user authentication (optional, defaults to false)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Genre)` - The requested genre
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let genre = service.get_genre(80, None).await?;
+    /// println!("{:?} (path: {:?})", genre.name, genre.path);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_genre(
+        &self,
+        genre_id: i32,
+        with_auth: Option<bool>,
+    ) -> Result<Genre, QobuzApiError> {
+        let params = vec![("genre_id".to_string(), genre_id.to_string())];
+
+        self.get_with_auth("/genre/get", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+}
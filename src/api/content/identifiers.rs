@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use crate::{
+    api::{content::batch::fetch_all, service::QobuzApiService},
+    errors::QobuzApiError::{self, InvalidParameterError},
+    models::{Album, Track},
+};
+
+/// Normalizes a UPC or EAN for comparison.
+///
+/// Whitespace and hyphens are removed, and leading zeros are stripped, so that 12-digit
+/// UPCs, 13-digit EANs and zero-padded spreadsheet values of the same product compare
+/// equal.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::identifiers::normalize_upc;
+///
+/// assert_eq!(normalize_upc("0886443927087"), normalize_upc("886443927087"));
+/// assert_eq!(normalize_upc(" 886-443-927087 "), "886443927087");
+/// ```
+pub fn normalize_upc(upc: &str) -> String {
+    upc.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .skip_while(|c| *c == '0')
+        .collect()
+}
+
+/// Normalizes an ISRC for comparison.
+///
+/// Whitespace and hyphens are removed and letters are uppercased, so that the display
+/// form `US-RC1-13-00001` and the compact form `usrc11300001` compare equal.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::identifiers::normalize_isrc;
+///
+/// assert_eq!(normalize_isrc("US-RC1-13-00001"), "USRC11300001");
+/// assert_eq!(normalize_isrc(" usrc11300001 "), "USRC11300001");
+/// ```
+pub fn normalize_isrc(isrc: &str) -> String {
+    isrc.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+impl QobuzApiService {
+    /// Finds the albums with the given UPC.
+    ///
+    /// The catalog is searched for the UPC, and only candidates whose UPC matches exactly
+    /// (after normalization with [`normalize_upc`]) are kept. The same product may be
+    /// available as several editions, so all matching albums are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `upc` - The UPC or EAN of the album, with or without leading zeros
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Album>)` - The matching albums, empty if none was found
+    /// * `Err(QobuzApiError)` - If the UPC is empty or the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// for album in service.find_album_by_upc("886443927087").await? {
+    ///     println!("{:?} ({:?})", album.title, album.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_album_by_upc(&self, upc: &str) -> Result<Vec<Album>, QobuzApiError> {
+        let normalized = normalize_upc(upc);
+        if normalized.is_empty() {
+            return Err(InvalidParameterError {
+                message: format!("Invalid UPC: {}", upc),
+            });
+        }
+
+        let mut seen = HashSet::new();
+        let mut albums = Vec::new();
+
+        // The catalog stores UPCs as 13-digit EANs, so search both forms of short UPCs
+        for query in identifier_queries(&normalized, 13) {
+            let result = self.search_albums(&query, None, None, None).await?;
+
+            for album in result.albums.and_then(|a| a.items).unwrap_or_default() {
+                if album.upc.as_deref().map(normalize_upc).as_deref() == Some(normalized.as_str())
+                    && seen.insert(album.id.clone())
+                {
+                    albums.push(album);
+                }
+            }
+        }
+
+        Ok(albums)
+    }
+
+    /// Finds the tracks with the given ISRC.
+    ///
+    /// The catalog is searched for the ISRC, and only candidates whose ISRC matches exactly
+    /// (after normalization with [`normalize_isrc`]) are kept. A recording usually appears
+    /// on several releases, so all matching tracks are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `isrc` - The ISRC of the recording, in compact or hyphenated form
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Track>)` - The matching tracks, empty if none was found
+    /// * `Err(QobuzApiError)` - If the ISRC is empty or the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let tracks = service.find_tracks_by_isrc("USQX91300108").await?;
+    /// println!("Found {} tracks", tracks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_tracks_by_isrc(&self, isrc: &str) -> Result<Vec<Track>, QobuzApiError> {
+        let normalized = normalize_isrc(isrc);
+        if normalized.is_empty() {
+            return Err(InvalidParameterError {
+                message: format!("Invalid ISRC: {}", isrc),
+            });
+        }
+
+        let result = self.search_tracks(&normalized, None, None, None).await?;
+
+        let mut seen = HashSet::new();
+        Ok(result
+            .tracks
+            .and_then(|t| t.items)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|track| {
+                track.isrc.as_deref().map(normalize_isrc).as_deref() == Some(normalized.as_str())
+            })
+            .filter(|track| seen.insert(track.id))
+            .collect())
+    }
+
+    /// Finds the albums for several UPCs, running a bounded number of lookups at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `upcs` - The UPCs to look up
+    /// * `concurrency` - The maximum number of lookups running at the same time (at least 1)
+    ///
+    /// # Returns
+    ///
+    /// A `(upc, result)` pair for each UPC, in the order of `upcs`. A failed lookup does not
+    /// affect the other lookups.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let upcs = ["886443927087", "0602527347305"];
+    ///
+    /// for (upc, result) in service.find_albums_by_upcs(&upcs, 4).await {
+    ///     match result {
+    ///         Ok(albums) if albums.is_empty() => println!("{}: not found", upc),
+    ///         Ok(albums) => println!("{}: {} editions", upc, albums.len()),
+    ///         Err(e) => println!("{}: lookup failed: {}", upc, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_albums_by_upcs(
+        &self,
+        upcs: &[&str],
+        concurrency: usize,
+    ) -> Vec<(String, Result<Vec<Album>, QobuzApiError>)> {
+        fetch_all(upcs, concurrency, |upc| self.find_album_by_upc(upc)).await
+    }
+
+    /// Finds the tracks for several ISRCs, running a bounded number of lookups at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `isrcs` - The ISRCs to look up
+    /// * `concurrency` - The maximum number of lookups running at the same time (at least 1)
+    ///
+    /// # Returns
+    ///
+    /// An `(isrc, result)` pair for each ISRC, in the order of `isrcs`. A failed lookup does
+    /// not affect the other lookups.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let isrcs = ["USQX91300108", "GBAYE0601498"];
+    ///
+    /// for (isrc, result) in service.find_tracks_by_isrcs(&isrcs, 4).await {
+    ///     println!("{}: {} tracks", isrc, result.map(|t| t.len()).unwrap_or(0));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_tracks_by_isrcs(
+        &self,
+        isrcs: &[&str],
+        concurrency: usize,
+    ) -> Vec<(String, Result<Vec<Track>, QobuzApiError>)> {
+        fetch_all(isrcs, concurrency, |isrc| self.find_tracks_by_isrc(isrc)).await
+    }
+}
+
+/// Returns the search queries for a normalized identifier: the identifier itself and,
+/// if it is shorter, its zero-padded form of `width` digits.
+fn identifier_queries(normalized: &str, width: usize) -> Vec<String> {
+    let mut queries = vec![normalized.to_string()];
+
+    if normalized.len() < width {
+        queries.push(format!("{:0>width$}", normalized, width = width));
+    }
+
+    queries
+}
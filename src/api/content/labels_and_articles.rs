@@ -1,9 +1,57 @@
+use futures_util::Stream;
+
 use crate::{
-    api::service::QobuzApiService,
+    api::{pagination::paginate, requests::AuthPolicy, service::QobuzApiService},
     errors::QobuzApiError::{self},
-    models::{Label, SearchResult},
+    models::{Album, Article, ItemSearchResult, Label, LabelList, SearchResult, Story},
 };
 
+/// Parameters for the label album list API.
+///
+/// This struct contains the configurable options for walking the catalog of a label,
+/// including sorting, pagination, and authentication preferences.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::labels_and_articles::LabelAlbumListParams;
+///
+/// let params = LabelAlbumListParams {
+///     sort: Some("release_date".to_string()),
+///     order: Some("desc".to_string()),
+///     limit: Some(100),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct LabelAlbumListParams {
+    /// Whether to execute the request with authentication (user_auth_token)
+    ///
+    /// When `Some(true)`, the request will be made with the authenticated user's token.
+    /// When `Some(false)` or `None`, the request will be made without authentication.
+    pub with_auth: Option<bool>,
+    /// The sorting criterion for albums
+    ///
+    /// Common values include "release_date", "title", "popularity", etc.
+    /// If `None`, the default sorting of the API will be used.
+    pub sort: Option<String>,
+    /// The order direction for sorting
+    ///
+    /// Use "asc" for ascending or "desc" for descending order.
+    /// If `None`, the default order of the API will be used.
+    pub order: Option<String>,
+    /// The maximum number of albums to return
+    ///
+    /// When streaming albums, this is the page size used for each request.
+    /// If `None`, defaults to 50 albums.
+    pub limit: Option<i32>,
+    /// The offset for pagination
+    ///
+    /// Use this to skip a certain number of albums from the beginning of the results.
+    /// If `None`, defaults to 0 (no offset).
+    pub offset: Option<i32>,
+}
+
 impl QobuzApiService {
     /// Searches for articles using the specified query.
     ///
@@ -60,9 +108,86 @@ impl QobuzApiService {
             ("offset".to_string(), offset.unwrap_or(0).to_string()),
         ];
 
-        let _use_auth = with_auth.unwrap_or(false);
+        self.get_with_auth("/article/search", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Gets the article with the specified article ID.
+    ///
+    /// This method retrieves a full article, including its HTML content. Use
+    /// [`html_to_text`](crate::utils::html_to_text) or
+    /// [`html_to_markdown`](crate::utils::html_to_markdown) to display the content.
+    ///
+    /// ## Parameters
+    ///
+    /// - `article_id`: The unique identifier of the article to retrieve
+    /// - `with_auth`: Optional flag to execute the request with user authentication (defaults to false)
+    ///
+    /// ## Returns
+    ///
+    /// - `Ok(Article)`: Contains the article and its content
+    /// - `Err(QobuzApiError)`: If the API request fails due to network issues, authentication problems, or invalid parameters
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError, utils::html_to_text};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let api = QobuzApiService::new().await?;
+    /// let article = api.get_article("12345", None).await?;
+    /// if let Some(content) = article.content {
+    ///     println!("{}", html_to_text(&content));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_article(
+        &self,
+        article_id: &str,
+        with_auth: Option<bool>,
+    ) -> Result<Article, QobuzApiError> {
+        let params = vec![("article_id".to_string(), article_id.to_string())];
+
+        self.get_with_auth("/article/get", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Gets the story with the specified story ID.
+    ///
+    /// This method retrieves a full editorial story, including its HTML content. Use
+    /// [`html_to_text`](crate::utils::html_to_text) or
+    /// [`html_to_markdown`](crate::utils::html_to_markdown) to display the content.
+    ///
+    /// ## Parameters
+    ///
+    /// - `story_id`: The unique identifier of the story to retrieve
+    /// - `with_auth`: Optional flag to execute the request with user authentication (defaults to false)
+    ///
+    /// ## Returns
+    ///
+    /// - `Ok(Story)`: Contains the story and its content
+    /// - `Err(QobuzApiError)`: If the API request fails due to network issues, authentication problems, or invalid parameters
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError, utils::html_to_markdown};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let api = QobuzApiService::new().await?;
+    /// let story = api.get_story("12345", None).await?;
+    /// let markdown = story.content.as_deref().map(html_to_markdown);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_story(
+        &self,
+        story_id: &str,
+        with_auth: Option<bool>,
+    ) -> Result<Story, QobuzApiError> {
+        let params = vec![("story_id".to_string(), story_id.to_string())];
 
-        self.get("/article/search", &params).await
+        self.get_with_auth("/story/get", &params, AuthPolicy::from(with_auth))
+            .await
     }
 
     /// Gets Label with the specified label ID.
@@ -125,8 +250,211 @@ impl QobuzApiService {
         params.push(("limit".to_string(), limit.unwrap_or(25).to_string()));
         params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
 
-        let _use_auth = with_auth.unwrap_or(false);
+        self.get_with_auth("/label/get", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Gets a page of albums released by the specified label.
+    ///
+    /// This method retrieves the albums of a label's catalog, one page at a time. Use
+    /// [`stream_label_albums`](Self::stream_label_albums) to walk the whole catalog
+    /// without handling pagination manually.
+    ///
+    /// ## Parameters
+    ///
+    /// - `label_id`: The unique identifier of the label whose albums to retrieve
+    /// - `params`: Sorting, pagination and authentication options for the request
+    ///
+    /// ## Returns
+    ///
+    /// - `Ok(ItemSearchResult<Album>)`: A page of the label's albums, along with the total album count
+    /// - `Err(QobuzApiError)`: If the API request fails due to network issues, authentication problems, or invalid parameters
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::labels_and_articles::LabelAlbumListParams;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let api = QobuzApiService::new().await?;
+    /// let params = LabelAlbumListParams {
+    ///     sort: Some("release_date".to_string()),
+    ///     limit: Some(20),
+    ///     ..Default::default()
+    /// };
+    /// let albums = api.get_label_albums("12345", params).await?;
+    /// println!("Label has {} albums", albums.total.unwrap_or(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_label_albums(
+        &self,
+        label_id: &str,
+        params: LabelAlbumListParams,
+    ) -> Result<ItemSearchResult<Album>, QobuzApiError> {
+        let mut query_params = vec![
+            ("label_id".to_string(), label_id.to_string()),
+            ("extra".to_string(), "albums".to_string()),
+        ];
+
+        if let Some(sort_val) = params.sort {
+            query_params.push(("sort".to_string(), sort_val));
+        }
+
+        if let Some(order_val) = params.order {
+            query_params.push(("order".to_string(), order_val));
+        }
+
+        query_params.push(("limit".to_string(), params.limit.unwrap_or(50).to_string()));
+        query_params.push(("offset".to_string(), params.offset.unwrap_or(0).to_string()));
+
+        let label: Label = self
+            .get_with_auth(
+                "/label/get",
+                &query_params,
+                AuthPolicy::from(params.with_auth),
+            )
+            .await?;
+
+        Ok(label.albums.unwrap_or_default())
+    }
+
+    /// Streams every album released by the specified label.
+    ///
+    /// This method walks the whole catalog of a label, requesting further pages from the
+    /// API only as the stream is consumed. The `limit` of `params` is used as the page
+    /// size, and `offset` as the position of the first album to yield.
+    ///
+    /// ## Parameters
+    ///
+    /// - `label_id`: The unique identifier of the label whose albums to stream
+    /// - `params`: Sorting, pagination and authentication options for the requests
+    ///
+    /// ## Returns
+    ///
+    /// A stream yielding each `Album` of the label. If a page request fails, the error is
+    /// yielded and the stream ends.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::labels_and_articles::LabelAlbumListParams;
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let api = QobuzApiService::new().await?;
+    /// let albums: Vec<_> = api
+    ///     .stream_label_albums("12345", LabelAlbumListParams::default())
+    ///     .try_collect()
+    ///     .await?;
+    /// println!("Collected {} albums", albums.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_label_albums<'a>(
+        &'a self,
+        label_id: &str,
+        params: LabelAlbumListParams,
+    ) -> impl Stream<Item = Result<Album, QobuzApiError>> + 'a {
+        let label_id = label_id.to_string();
+        let start_offset = params.offset.unwrap_or(0);
+        let page_size = params.limit.unwrap_or(50);
+
+        paginate(start_offset, page_size, move |limit, offset| {
+            let label_id = label_id.clone();
+            let page_params = LabelAlbumListParams {
+                limit: Some(limit),
+                offset: Some(offset),
+                ..params.clone()
+            };
+
+            async move { self.get_label_albums(&label_id, page_params).await }
+        })
+    }
+
+    /// Lists the labels available on the Qobuz platform.
+    ///
+    /// ## Parameters
+    ///
+    /// - `limit`: Optional maximum number of labels to return (defaults to 50 if not specified)
+    /// - `offset`: Optional offset for pagination (defaults to 0 if not specified)
+    /// - `with_auth`: Optional flag to execute the request with user authentication (defaults to false)
+    ///
+    /// ## Returns
+    ///
+    /// - `Ok(LabelList)`: Contains a page of labels
+    /// - `Err(QobuzApiError)`: If the API request fails due to network issues, authentication problems, or invalid parameters
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let api = QobuzApiService::new().await?;
+    /// let result = api.list_labels(Some(100), None, None).await?;
+    /// for label in result.labels.and_then(|l| l.items).unwrap_or_default() {
+    ///     println!("{:?}", label.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_labels(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        with_auth: Option<bool>,
+    ) -> Result<LabelList, QobuzApiError> {
+        let params = vec![
+            ("limit".to_string(), limit.unwrap_or(50).to_string()),
+            ("offset".to_string(), offset.unwrap_or(0).to_string()),
+        ];
+
+        self.get_with_auth("/label/list", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Searches for labels using the specified query.
+    ///
+    /// ## Parameters
+    ///
+    /// - `query`: The search query string to match against label names
+    /// - `limit`: Optional maximum number of results to return (defaults to 50 if not specified)
+    /// - `offset`: Optional offset for pagination (defaults to 0 if not specified)
+    /// - `with_auth`: Optional flag to execute the search with user authentication (defaults to false)
+    ///
+    /// ## Returns
+    ///
+    /// - `Ok(LabelList)`: Contains the labels that match the query
+    /// - `Err(QobuzApiError)`: If the API request fails due to network issues, authentication problems, or invalid parameters
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let api = QobuzApiService::new().await?;
+    /// let result = api.search_labels("Deutsche Grammophon", Some(5), None, None).await?;
+    /// if let Some(label) = result.labels.and_then(|l| l.items).and_then(|items| items.into_iter().next()) {
+    ///     println!("Best match: {:?} ({:?})", label.name, label.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_labels(
+        &self,
+        query: &str,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        with_auth: Option<bool>,
+    ) -> Result<LabelList, QobuzApiError> {
+        let params = vec![
+            ("query".to_string(), query.to_string()),
+            ("limit".to_string(), limit.unwrap_or(50).to_string()),
+            ("offset".to_string(), offset.unwrap_or(0).to_string()),
+        ];
 
-        self.get("/label/get", &params).await
+        self.get_with_auth("/label/search", &params, AuthPolicy::from(with_auth))
+            .await
     }
 }
@@ -0,0 +1,201 @@
+use std::str::FromStr;
+
+use url::Url;
+
+use crate::{
+    api::service::QobuzApiService,
+    errors::QobuzApiError::{self, InvalidParameterError, UrlError},
+    models::{Album, Artist, Label, Playlist, Track},
+};
+
+/// A reference to a Qobuz resource, parsed from a Qobuz link.
+///
+/// The following link formats are supported:
+///
+/// - Player links, such as `https://play.qobuz.com/album/0886443927087`
+/// - Open links, such as `https://open.qobuz.com/track/40128300`
+/// - Store links, such as `https://www.qobuz.com/us-en/album/random-access-memories-daft-punk/0886443927087`
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::api::content::links::QobuzLink;
+///
+/// let link: QobuzLink = "https://open.qobuz.com/track/40128300".parse().unwrap();
+/// assert_eq!(link, QobuzLink::Track("40128300".to_string()));
+///
+/// let link = QobuzLink::parse("https://play.qobuz.com/album/0886443927087").unwrap();
+/// assert_eq!(link, QobuzLink::Album("0886443927087".to_string()));
+///
+/// let link = QobuzLink::parse(
+///     "https://www.qobuz.com/us-en/album/random-access-memories-daft-punk/0886443927087",
+/// )
+/// .unwrap();
+/// assert_eq!(link.id(), "0886443927087");
+///
+/// let link = QobuzLink::parse("https://www.qobuz.com/gb-en/interpreter/daft-punk/36819").unwrap();
+/// assert_eq!(link, QobuzLink::Artist("36819".to_string()));
+///
+/// assert!(QobuzLink::parse("https://example.com/album/123").is_err());
+/// assert!(QobuzLink::parse("https://open.qobuz.com/album/0886443927087/tracks").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QobuzLink {
+    /// A link to an album, with the album ID
+    Album(String),
+    /// A link to a track, with the track ID
+    Track(String),
+    /// A link to an artist, with the artist ID
+    Artist(String),
+    /// A link to a playlist, with the playlist ID
+    Playlist(String),
+    /// A link to a label, with the label ID
+    Label(String),
+}
+
+impl QobuzLink {
+    /// Parses a Qobuz player, open or store link.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UrlError` if the input is not a valid URL, and `InvalidParameterError` if
+    /// it is not a link to a supported Qobuz resource.
+    pub fn parse(link: &str) -> Result<Self, QobuzApiError> {
+        let url = Url::parse(link.trim()).map_err(UrlError)?;
+
+        let host = url.host_str().unwrap_or_default();
+        if host != "qobuz.com" && !host.ends_with(".qobuz.com") {
+            return Err(InvalidParameterError {
+                message: format!("Not a Qobuz link: {}", link),
+            });
+        }
+
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+
+        // Player and open links are `/<kind>/<id>`, store links `/<locale>/<kind>/<slug>/<id>`,
+        // with more slugs for some kinds
+        let link_kind = match segments.as_slice() {
+            [kind, id] => Self::kind_from_segment(kind).map(|kind| (kind, id)),
+            [_, kind, _, .., id] => Self::kind_from_segment(kind).map(|kind| (kind, id)),
+            _ => None,
+        };
+
+        link_kind
+            .filter(|(_, id)| id.chars().all(|c| c.is_ascii_alphanumeric()))
+            .map(|(kind, id)| kind(id.to_string()))
+            .ok_or_else(|| InvalidParameterError {
+                message: format!("Unsupported Qobuz link: {}", link),
+            })
+    }
+
+    /// Maps a path segment to the corresponding kind of link.
+    fn kind_from_segment(segment: &str) -> Option<fn(String) -> Self> {
+        match segment {
+            "album" => Some(Self::Album),
+            "track" => Some(Self::Track),
+            "artist" | "interpreter" => Some(Self::Artist),
+            "playlist" | "playlists" => Some(Self::Playlist),
+            "label" => Some(Self::Label),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the referenced resource.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Album(id)
+            | Self::Track(id)
+            | Self::Artist(id)
+            | Self::Playlist(id)
+            | Self::Label(id) => id,
+        }
+    }
+}
+
+impl FromStr for QobuzLink {
+    type Err = QobuzApiError;
+
+    fn from_str(link: &str) -> Result<Self, Self::Err> {
+        Self::parse(link)
+    }
+}
+
+/// A Qobuz resource fetched by resolving a [`QobuzLink`].
+#[derive(Debug, Clone)]
+pub enum QobuzResource {
+    /// The referenced album
+    Album(Box<Album>),
+    /// The referenced track
+    Track(Box<Track>),
+    /// The referenced artist
+    Artist(Box<Artist>),
+    /// The referenced playlist
+    Playlist(Box<Playlist>),
+    /// The referenced label
+    Label(Box<Label>),
+}
+
+impl QobuzApiService {
+    /// Fetches the resource referenced by a Qobuz link.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - The parsed link to resolve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QobuzResource)` - The referenced album, track, artist, playlist or label
+    /// * `Err(QobuzApiError)` - If the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::links::{QobuzLink, QobuzResource};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let link = QobuzLink::parse("https://play.qobuz.com/album/0886443927087")?;
+    ///
+    /// if let QobuzResource::Album(album) = service.resolve(&link).await? {
+    ///     println!("{:?}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve(&self, link: &QobuzLink) -> Result<QobuzResource, QobuzApiError> {
+        Ok(match link {
+            QobuzLink::Album(id) => {
+                QobuzResource::Album(Box::new(self.get_album(id, None, None, None, None).await?))
+            }
+            QobuzLink::Track(id) => QobuzResource::Track(Box::new(self.get_track(id, None).await?)),
+            QobuzLink::Artist(id) => QobuzResource::Artist(Box::new(
+                self.get_artist(id, None, None, None, None, None).await?,
+            )),
+            QobuzLink::Playlist(id) => QobuzResource::Playlist(Box::new(
+                self.get_playlist(id, None, None, None, None).await?,
+            )),
+            QobuzLink::Label(id) => {
+                QobuzResource::Label(Box::new(self.get_label(id, None, None, None, None).await?))
+            }
+        })
+    }
+
+    /// Parses a Qobuz link and fetches the resource it references.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - A Qobuz player, open or store link
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QobuzResource)` - The referenced album, track, artist, playlist or label
+    /// * `Err(QobuzApiError)` - If the link cannot be parsed or the API request fails
+    pub async fn resolve_url(&self, link: &str) -> Result<QobuzResource, QobuzApiError> {
+        let link = QobuzLink::parse(link)?;
+
+        self.resolve(&link).await
+    }
+}
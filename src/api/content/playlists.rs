@@ -1,9 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use futures_util::{Stream, StreamExt, TryStreamExt, stream::iter};
+
 use crate::{
-    api::service::QobuzApiService,
-    errors::QobuzApiError::{self},
-    models::{Playlist, SearchResult},
+    api::{
+        content::batch::DEFAULT_BATCH_CONCURRENCY, pagination::paginate, requests::AuthPolicy,
+        service::QobuzApiService,
+    },
+    download::{
+        DownloadOptions,
+        m3u8::{M3u8Entry, relative_path, write_m3u8},
+        outcome::{DownloadOutcome, extension_for_format_id},
+        template::{PathTemplate, TemplateValues},
+    },
+    errors::QobuzApiError::{self, InvalidParameterError, ResourceNotFoundError},
+    models::{Playlist, QobuzApiStatusResponse, SearchResult, Track, UserPlaylists},
+    utils::sanitize_filename,
 };
 
+/// Parameters for updating an existing playlist.
+///
+/// Every field is optional; only the fields that are set are sent to the API, so
+/// the remaining properties of the playlist are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::playlists::PlaylistUpdateParams;
+///
+/// let params = PlaylistUpdateParams {
+///     name: Some("Road Trip".to_string()),
+///     is_public: Some(false),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct PlaylistUpdateParams {
+    /// The new name of the playlist
+    ///
+    /// If `None`, the current name is kept.
+    pub name: Option<String>,
+    /// The new description of the playlist
+    ///
+    /// If `None`, the current description is kept.
+    pub description: Option<String>,
+    /// Whether the playlist should be visible to other users
+    ///
+    /// If `None`, the current visibility is kept.
+    pub is_public: Option<bool>,
+    /// Whether other users should be allowed to edit the playlist
+    ///
+    /// If `None`, the current collaboration setting is kept.
+    pub is_collaborative: Option<bool>,
+}
+
+/// How the tracks of a downloaded playlist are laid out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PlaylistLayout {
+    /// Each track in the folder of its album, named after
+    /// [`DownloadOptions::album_template`] and [`DownloadOptions::track_template`] as in
+    /// album downloads
+    #[default]
+    Albums,
+    /// Every track in the playlist folder, named after
+    /// [`PathTemplate::DEFAULT_FLAT_TRACK`], `{artist} - {title}`
+    Flat,
+}
+
+/// The result of [`QobuzApiService::download_playlist`].
+#[derive(Debug)]
+pub struct PlaylistDownload {
+    /// The path of the M3U8 playlist file
+    pub playlist_path: String,
+    /// A `(track_id, result)` pair for each track, in playlist order. A track appearing
+    /// several times in the playlist is downloaded and listed once.
+    pub tracks: Vec<(String, Result<DownloadOutcome, QobuzApiError>)>,
+}
+
+impl PlaylistDownload {
+    /// Returns the outcome of every track downloaded or reused.
+    pub fn outcomes(&self) -> impl Iterator<Item = &DownloadOutcome> {
+        self.tracks
+            .iter()
+            .filter_map(|(_, result)| result.as_ref().ok())
+    }
+
+    /// Returns the tracks that failed, with their error.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &QobuzApiError)> {
+        self.tracks
+            .iter()
+            .filter_map(|(track_id, result)| result.as_ref().err().map(|e| (track_id.as_str(), e)))
+    }
+}
+
 impl QobuzApiService {
     /// Retrieves a specific playlist by its ID from the Qobuz API.
     ///
@@ -69,9 +158,8 @@ impl QobuzApiService {
         params.push(("limit".to_string(), limit.unwrap_or(25).to_string()));
         params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
 
-        let _use_auth = with_auth.unwrap_or(false);
-
-        self.get("/playlist/get", &params).await
+        self.get_with_auth("/playlist/get", &params, AuthPolicy::from(with_auth))
+            .await
     }
 
     /// Searches for playlists using a text query through the Qobuz API.
@@ -129,8 +217,657 @@ impl QobuzApiService {
             ("offset".to_string(), offset.unwrap_or(0).to_string()),
         ];
 
-        let _use_auth = with_auth.unwrap_or(false);
+        self.get_with_auth("/playlist/search", &params, AuthPolicy::from(with_auth))
+            .await
+    }
+
+    /// Creates a new playlist for the authenticated user.
+    ///
+    /// The playlist is created empty; use [`add_playlist_tracks`](Self::add_playlist_tracks)
+    /// to fill it afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the new playlist
+    /// * `description` - Optional description of the playlist
+    /// * `is_public` - Optional flag to make the playlist visible to other users.
+    ///   Defaults to `false` if not specified.
+    /// * `is_collaborative` - Optional flag to allow other users to edit the playlist.
+    ///   Defaults to `false` if not specified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let mut service = QobuzApiService::new().await?;
+    /// service.authenticate_with_env().await?;
+    ///
+    /// let playlist = service
+    ///     .create_playlist("Late Night Jazz", Some("Slow and smoky"), Some(false), None)
+    ///     .await?;
+    /// println!("Created playlist {:?}", playlist.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Playlist)` - The newly created playlist
+    /// * `Err(QobuzApiError)` - If the user is not authenticated or the API request fails
+    pub async fn create_playlist(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        is_public: Option<bool>,
+        is_collaborative: Option<bool>,
+    ) -> Result<Playlist, QobuzApiError> {
+        let mut params = vec![("name".to_string(), name.to_string())];
+
+        if let Some(description_val) = description {
+            params.push(("description".to_string(), description_val.to_string()));
+        }
+
+        params.push((
+            "is_public".to_string(),
+            is_public.unwrap_or(false).to_string(),
+        ));
+        params.push((
+            "is_collaborative".to_string(),
+            is_collaborative.unwrap_or(false).to_string(),
+        ));
+
+        self.post_with_auth("/playlist/create", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Updates the name, description or visibility of an existing playlist.
+    ///
+    /// Only the fields set in `params` are sent to the API. The playlist must be
+    /// owned by the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to update
+    /// * `params` - The properties to change
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::playlists::PlaylistUpdateParams;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let params = PlaylistUpdateParams {
+    ///     name: Some("Early Morning Jazz".to_string()),
+    ///     is_public: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let playlist = service.update_playlist("12345", params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Playlist)` - The updated playlist
+    /// * `Err(QobuzApiError)` - If no property was set, the user is not allowed to edit
+    ///   the playlist, or the API request fails
+    pub async fn update_playlist(
+        &self,
+        playlist_id: &str,
+        params: PlaylistUpdateParams,
+    ) -> Result<Playlist, QobuzApiError> {
+        let mut query_params = Vec::new();
+
+        if let Some(name_val) = params.name {
+            query_params.push(("name".to_string(), name_val));
+        }
+
+        if let Some(description_val) = params.description {
+            query_params.push(("description".to_string(), description_val));
+        }
+
+        if let Some(is_public_val) = params.is_public {
+            query_params.push(("is_public".to_string(), is_public_val.to_string()));
+        }
+
+        if let Some(is_collaborative_val) = params.is_collaborative {
+            query_params.push((
+                "is_collaborative".to_string(),
+                is_collaborative_val.to_string(),
+            ));
+        }
+
+        // At least one property must be changed
+        if query_params.is_empty() {
+            return Err(InvalidParameterError {
+                message: "At least one playlist property (name, description, is_public or is_collaborative) must be provided".to_string(),
+            });
+        }
+
+        query_params.insert(0, ("playlist_id".to_string(), playlist_id.to_string()));
+
+        self.post_with_auth("/playlist/update", &query_params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Deletes a playlist owned by the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to delete
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let response = service.delete_playlist("12345").await?;
+    /// println!("Status: {:?}", response.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QobuzApiStatusResponse)` - Response indicating if the playlist was deleted
+    /// * `Err(QobuzApiError)` - If the user does not own the playlist or the API request fails
+    pub async fn delete_playlist(
+        &self,
+        playlist_id: &str,
+    ) -> Result<QobuzApiStatusResponse, QobuzApiError> {
+        let params = vec![("playlist_id".to_string(), playlist_id.to_string())];
+
+        self.post_with_auth("/playlist/delete", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Appends tracks to a playlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to add the tracks to
+    /// * `track_ids` - IDs of the tracks to add, in the order they should appear
+    /// * `no_duplicate` - Optional flag to skip tracks that are already in the playlist.
+    ///   Defaults to `true` if not specified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let playlist = service
+    ///     .add_playlist_tracks("12345", &["40128300", "13176083"], None)
+    ///     .await?;
+    /// println!("Playlist now has {:?} tracks", playlist.tracks_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Playlist)` - The updated playlist
+    /// * `Err(QobuzApiError)` - If no track IDs are given, the user is not allowed to edit
+    ///   the playlist, or the API request fails
+    pub async fn add_playlist_tracks(
+        &self,
+        playlist_id: &str,
+        track_ids: &[&str],
+        no_duplicate: Option<bool>,
+    ) -> Result<Playlist, QobuzApiError> {
+        if track_ids.is_empty() {
+            return Err(InvalidParameterError {
+                message: "At least one track ID must be provided".to_string(),
+            });
+        }
+
+        let params = vec![
+            ("playlist_id".to_string(), playlist_id.to_string()),
+            ("track_ids".to_string(), track_ids.join(",")),
+            (
+                "no_duplicate".to_string(),
+                no_duplicate.unwrap_or(true).to_string(),
+            ),
+        ];
+
+        self.post_with_auth("/playlist/addTracks", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Removes entries from a playlist.
+    ///
+    /// Playlist entries are identified by their playlist track ID rather than the track ID,
+    /// since the same track may appear more than once in a playlist. The playlist track ID
+    /// is available in [`Track::playlist_track_id`](crate::models::Track::playlist_track_id)
+    /// for tracks returned by [`get_playlist`](Self::get_playlist) with `extra` set to `"tracks"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to remove the entries from
+    /// * `playlist_track_ids` - Playlist track IDs of the entries to remove
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let playlist = service.delete_playlist_tracks("12345", &["987654321"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Playlist)` - The updated playlist
+    /// * `Err(QobuzApiError)` - If no playlist track IDs are given, the user is not allowed
+    ///   to edit the playlist, or the API request fails
+    pub async fn delete_playlist_tracks(
+        &self,
+        playlist_id: &str,
+        playlist_track_ids: &[&str],
+    ) -> Result<Playlist, QobuzApiError> {
+        if playlist_track_ids.is_empty() {
+            return Err(InvalidParameterError {
+                message: "At least one playlist track ID must be provided".to_string(),
+            });
+        }
+
+        let params = vec![
+            ("playlist_id".to_string(), playlist_id.to_string()),
+            (
+                "playlist_track_ids".to_string(),
+                playlist_track_ids.join(","),
+            ),
+        ];
+
+        self.post_with_auth("/playlist/deleteTracks", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Moves entries of a playlist to a new position.
+    ///
+    /// The given entries are moved, in the given order, in front of the entry currently
+    /// at position `insert_before` (zero-based).
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to reorder
+    /// * `playlist_track_ids` - Playlist track IDs of the entries to move
+    /// * `insert_before` - Zero-based position in front of which the entries are inserted
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// // Move an entry to the top of the playlist
+    /// let playlist = service
+    ///     .update_playlist_tracks_position("12345", &["987654321"], 0)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Playlist)` - The updated playlist
+    /// * `Err(QobuzApiError)` - If no playlist track IDs are given, the user is not allowed
+    ///   to edit the playlist, or the API request fails
+    pub async fn update_playlist_tracks_position(
+        &self,
+        playlist_id: &str,
+        playlist_track_ids: &[&str],
+        insert_before: i32,
+    ) -> Result<Playlist, QobuzApiError> {
+        if playlist_track_ids.is_empty() {
+            return Err(InvalidParameterError {
+                message: "At least one playlist track ID must be provided".to_string(),
+            });
+        }
+
+        let params = vec![
+            ("playlist_id".to_string(), playlist_id.to_string()),
+            (
+                "playlist_track_ids".to_string(),
+                playlist_track_ids.join(","),
+            ),
+            ("insert_before".to_string(), insert_before.to_string()),
+        ];
+
+        self.post_with_auth(
+            "/playlist/updateTracksPosition",
+            &params,
+            AuthPolicy::Required,
+        )
+        .await
+    }
+
+    /// Subscribes the authenticated user to another user's playlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to subscribe to
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let response = service.subscribe_playlist("12345").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QobuzApiStatusResponse)` - Response indicating if the subscription succeeded
+    /// * `Err(QobuzApiError)` - If the user is not authenticated or the API request fails
+    pub async fn subscribe_playlist(
+        &self,
+        playlist_id: &str,
+    ) -> Result<QobuzApiStatusResponse, QobuzApiError> {
+        let params = vec![("playlist_id".to_string(), playlist_id.to_string())];
+
+        self.post_with_auth("/playlist/subscribe", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Unsubscribes the authenticated user from another user's playlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to unsubscribe from
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let response = service.unsubscribe_playlist("12345").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QobuzApiStatusResponse)` - Response indicating if the unsubscription succeeded
+    /// * `Err(QobuzApiError)` - If the user is not authenticated or the API request fails
+    pub async fn unsubscribe_playlist(
+        &self,
+        playlist_id: &str,
+    ) -> Result<QobuzApiStatusResponse, QobuzApiError> {
+        let params = vec![("playlist_id".to_string(), playlist_id.to_string())];
+
+        self.post_with_auth("/playlist/unsubscribe", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Lists the playlists of the authenticated user.
+    ///
+    /// The result contains both the playlists owned by the user and the playlists
+    /// the user is subscribed to.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Optional integer specifying the maximum number of playlists to return.
+    ///   Defaults to 50 if not specified.
+    /// * `offset` - Optional integer specifying the offset of the first playlist to return,
+    ///   used for pagination. Defaults to 0 if not specified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let result = service.get_user_playlists(Some(100), None).await?;
+    /// if let Some(playlists) = result.playlists {
+    ///     println!("User has {} playlists", playlists.total.unwrap_or(0));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(UserPlaylists)` - The user and a page of their playlists
+    /// * `Err(QobuzApiError)` - If the user is not authenticated or the API request fails
+    pub async fn get_user_playlists(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<UserPlaylists, QobuzApiError> {
+        let params = vec![
+            ("limit".to_string(), limit.unwrap_or(50).to_string()),
+            ("offset".to_string(), offset.unwrap_or(0).to_string()),
+        ];
+
+        self.get_with_auth("/playlist/getUserPlaylists", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Streams every track of a playlist, in playlist order.
+    ///
+    /// Further pages are requested from the API only as the stream is consumed. The
+    /// request is made with the user authentication token if available, so that private
+    /// playlists of the user can be read.
+    ///
+    /// # Returns
+    /// A stream yielding each `Track` of the playlist. If a page request fails, the error
+    /// is yielded and the stream ends.
+    pub fn stream_playlist_tracks<'a>(
+        &'a self,
+        playlist_id: &'a str,
+    ) -> impl Stream<Item = Result<Track, QobuzApiError>> + 'a {
+        paginate(0, 500, move |limit, offset| async move {
+            let playlist = self
+                .get_playlist(
+                    playlist_id,
+                    Some(true),
+                    Some("tracks"),
+                    Some(limit),
+                    Some(offset),
+                )
+                .await?;
+            Ok(playlist.tracks.unwrap_or_default())
+        })
+        .map_ok(|track| *track)
+    }
+
+    /// Downloads the tracks of a playlist and writes an extended M3U8 playlist file.
+    ///
+    /// The tracks are downloaded with [`download_track`](Self::download_track), several at
+    /// a time as set by [`DownloadOptions::concurrency`], and laid out under `path` as set
+    /// by `layout`. Set [`DownloadOptions::library`] to reuse the tracks already downloaded
+    /// into a library, from their recorded path, instead of fetching them again. Without a
+    /// library, every track is downloaded again, overwriting any file at its target path.
+    ///
+    /// The playlist file is saved as `{path}/{playlist name}.m3u8`. It lists the tracks in
+    /// playlist order, with their duration and "Artist - Title", and paths relative to the
+    /// playlist file. Tracks that failed to download are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier of the playlist to download
+    /// * `format_id` - The format ID specifying the audio quality (see
+    ///   [`download_album`](Self::download_album))
+    /// * `path` - The folder the tracks and the playlist file are saved in
+    /// * `layout` - Whether the tracks are saved in album folders or all in `path`
+    /// * `options` - Download options for the tracks
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PlaylistDownload)` - The path of the playlist file and the outcome of each
+    ///   track. A track that fails does not stop the others.
+    /// * `Err(QobuzApiError)` - If the playlist cannot be retrieved or the playlist file
+    ///   cannot be written
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::{
+    /// #     QobuzApiService, QobuzApiError,
+    /// #     api::content::playlists::PlaylistLayout, download::DownloadOptions,
+    /// # };
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let mut service = QobuzApiService::new().await?;
+    /// service.authenticate_with_env().await?;
+    ///
+    /// let download = service
+    ///     .download_playlist("1234567", "6", "downloads", PlaylistLayout::Albums, &DownloadOptions::default())
+    ///     .await?;
+    /// println!("Playlist saved to {}", download.playlist_path);
+    /// for (track_id, error) in download.failed() {
+    ///     println!("Track {} failed: {}", track_id, error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_playlist(
+        &self,
+        playlist_id: &str,
+        format_id: &str,
+        path: &str,
+        layout: PlaylistLayout,
+        options: &DownloadOptions,
+    ) -> Result<PlaylistDownload, QobuzApiError> {
+        let playlist = self
+            .get_playlist(playlist_id, Some(true), None, None, None)
+            .await?;
+        let entries: Vec<Track> = self
+            .stream_playlist_tracks(playlist_id)
+            .try_collect()
+            .await?;
+
+        // A track may appear several times in a playlist, but is downloaded once
+        let mut seen = HashSet::new();
+        let track_ids: Vec<String> = entries
+            .iter()
+            .filter_map(|track| track.id)
+            .map(|id| id.to_string())
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+
+        // Playlists only hold an outline of the albums, so fetch the tracks for the layout
+        let track_id_refs: Vec<&str> = track_ids.iter().map(String::as_str).collect();
+        let tracks = self
+            .get_tracks(&track_id_refs, None, DEFAULT_BATCH_CONCURRENCY)
+            .await;
+
+        let flat_template = PathTemplate::parse(PathTemplate::DEFAULT_FLAT_TRACK)
+            .expect("the default flat track template is valid");
+        // Paths are compared case-insensitively, as some file systems do
+        let mut paths: HashSet<String> = HashSet::new();
+        let jobs: Vec<(String, Result<String, QobuzApiError>)> = tracks
+            .into_iter()
+            .map(|(track_id, track)| {
+                let track_path = track.and_then(|track| {
+                    let album = track
+                        .album
+                        .as_deref()
+                        .ok_or_else(|| ResourceNotFoundError {
+                            resource_type: "album".to_string(),
+                            resource_id: track_id.clone(),
+                        })?;
+                    let values = TemplateValues::for_track(&track, album, format_id);
+                    let relative = match layout {
+                        PlaylistLayout::Albums => format!(
+                            "{}/{}",
+                            options.album_template.render(&values),
+                            options.track_template.render(&values)
+                        ),
+                        PlaylistLayout::Flat => flat_template.render(&values),
+                    };
+
+                    // Tell apart different tracks that would be saved to the same path
+                    let mut track_path = format!("{}/{}", path, relative);
+                    let mut copy = 1;
+                    while !paths.insert(track_path.to_lowercase()) {
+                        track_path = match copy {
+                            1 => format!("{}/{} [{}]", path, relative, track_id),
+                            _ => format!("{}/{} [{} {}]", path, relative, track_id, copy),
+                        };
+                        copy += 1;
+                    }
+                    Ok(format!(
+                        "{}.{}",
+                        track_path,
+                        extension_for_format_id(format_id)
+                    ))
+                });
+                (track_id, track_path)
+            })
+            .collect();
+
+        // The downloads are created up front to keep the returned future `Send`
+        let downloads: Vec<_> = jobs
+            .into_iter()
+            .map(|(track_id, track_path)| async move {
+                let result = match track_path {
+                    Ok(track_path) => {
+                        self.download_track(&track_id, format_id, &track_path, options)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+                (track_id, result)
+            })
+            .collect();
+
+        let results: Vec<(String, Result<DownloadOutcome, QobuzApiError>)> = iter(downloads)
+            .buffered(options.concurrency.max(1))
+            .collect()
+            .await;
+
+        let name = playlist
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("Playlist {}", playlist_id));
+        let playlist_path = format!("{}/{}.m3u8", path, sanitize_filename(&name));
+
+        // List every occurrence of the downloaded tracks, in playlist order
+        let saved_paths: HashMap<&str, &str> = results
+            .iter()
+            .filter_map(|(track_id, result)| {
+                result
+                    .as_ref()
+                    .ok()
+                    .map(|outcome| (track_id.as_str(), outcome.path.as_str()))
+            })
+            .collect();
+        let mut m3u8_entries = Vec::with_capacity(entries.len());
+        for track in &entries {
+            let Some(saved_path) = track
+                .id
+                .and_then(|id| saved_paths.get(id.to_string().as_str()).copied())
+            else {
+                continue;
+            };
+
+            let title = track.title.clone().unwrap_or_default();
+            let title = match track.performer.as_ref().and_then(|p| p.name.as_deref()) {
+                Some(artist) => format!("{} - {}", artist, title),
+                None => title,
+            };
+            m3u8_entries.push(M3u8Entry {
+                path: relative_path(path, saved_path)?,
+                duration: track.duration,
+                title,
+            });
+        }
+
+        write_m3u8(&playlist_path, Some(&name), &m3u8_entries)?;
 
-        self.get("/playlist/search", &params).await
+        Ok(PlaylistDownload {
+            playlist_path,
+            tracks: results,
+        })
     }
 }
@@ -0,0 +1,289 @@
+use std::collections::{HashSet, VecDeque};
+
+use futures_util::{Stream, stream::try_unfold};
+
+use crate::{
+    api::service::QobuzApiService,
+    errors::QobuzApiError::{self, InvalidParameterError, ResourceNotFoundError},
+    models::Track,
+};
+
+/// Configuration of a radio track feed.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::radio::RadioConfig;
+///
+/// // A very diverse radio without explicit content
+/// let config = RadioConfig {
+///     tracks_per_artist: 1,
+///     allow_explicit: false,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadioConfig {
+    /// Maximum number of tracks taken from each artist.
+    ///
+    /// This controls the diversity of the radio: lower values move on to other
+    /// artists sooner. Must be at least 1. Defaults to 3.
+    pub tracks_per_artist: usize,
+    /// Maximum number of similar artists followed from each visited artist.
+    ///
+    /// Higher values spread the radio wider around the seed. Defaults to 10.
+    pub similar_artists_per_artist: i32,
+    /// Whether tracks with a parental warning may be queued. Defaults to `true`.
+    pub allow_explicit: bool,
+}
+
+impl RadioConfig {
+    /// Checks that the radio can produce tracks with this configuration.
+    fn validate(&self) -> Result<(), QobuzApiError> {
+        if self.tracks_per_artist == 0 {
+            return Err(InvalidParameterError {
+                message: "A radio needs at least one track per artist".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for RadioConfig {
+    fn default() -> Self {
+        Self {
+            tracks_per_artist: 3,
+            similar_artists_per_artist: 10,
+            allow_explicit: true,
+        }
+    }
+}
+
+/// A non-repeating radio track feed built from a seed artist.
+///
+/// The radio walks the similar-artist graph breadth first, starting at the seed
+/// artist, and queues the top tracks of each visited artist. No track and no artist
+/// is visited twice. The feed only ends once every artist reachable from the seed has
+/// been visited, which in practice makes it endless.
+///
+/// Radios are created with [`QobuzApiService::artist_radio`] or
+/// [`QobuzApiService::album_radio`].
+pub struct Radio<'a> {
+    service: &'a QobuzApiService,
+    config: RadioConfig,
+    pending_artists: VecDeque<i32>,
+    visited_artists: HashSet<i32>,
+    played_tracks: HashSet<i32>,
+    queued_tracks: VecDeque<Track>,
+}
+
+impl<'a> Radio<'a> {
+    fn new(service: &'a QobuzApiService, seed_artist_id: i32, config: RadioConfig) -> Self {
+        Self {
+            service,
+            config,
+            pending_artists: VecDeque::from([seed_artist_id]),
+            visited_artists: HashSet::from([seed_artist_id]),
+            played_tracks: HashSet::new(),
+            queued_tracks: VecDeque::new(),
+        }
+    }
+
+    /// Marks tracks as already played, so the radio never queues them.
+    pub fn exclude_tracks<I: IntoIterator<Item = i32>>(&mut self, track_ids: I) {
+        self.played_tracks.extend(track_ids);
+    }
+
+    /// Returns the next track of the radio.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Track))` - The next track to play
+    /// * `Ok(None)` - If every artist reachable from the seed has been visited
+    /// * `Err(QobuzApiError)` - If an API request fails. The radio can still be used
+    ///   afterwards, and retries the failed artist on the next call.
+    pub async fn next_track(&mut self) -> Result<Option<Track>, QobuzApiError> {
+        loop {
+            if let Some(track) = self.queued_tracks.pop_front() {
+                return Ok(Some(track));
+            }
+
+            let Some(artist_id) = self.pending_artists.pop_front() else {
+                return Ok(None);
+            };
+
+            // Keep the artist pending, so that its visit is retried on the next call
+            if let Err(e) = self.visit_artist(artist_id).await {
+                self.pending_artists.push_front(artist_id);
+                return Err(e);
+            }
+        }
+    }
+
+    /// Queues the top tracks of an artist and schedules its similar artists.
+    ///
+    /// Both requests are made before the radio is changed, so that a failed visit can be
+    /// retried as a whole.
+    async fn visit_artist(&mut self, artist_id: i32) -> Result<(), QobuzApiError> {
+        let artist_id = artist_id.to_string();
+
+        // Request more tracks than needed, since some may be filtered out
+        let top_tracks = self
+            .service
+            .get_artist_top_tracks(&artist_id, Some(self.config.tracks_per_artist as i32 * 3))
+            .await?;
+        let similar = self
+            .service
+            .get_similar_artists(
+                &artist_id,
+                Some(self.config.similar_artists_per_artist),
+                None,
+                None,
+            )
+            .await?;
+
+        let mut taken = 0;
+        for track in top_tracks {
+            if taken >= self.config.tracks_per_artist {
+                break;
+            }
+
+            if !self.config.allow_explicit && track.parental_warning == Some(true) {
+                continue;
+            }
+
+            if let Some(track_id) = track.id
+                && self.played_tracks.insert(track_id)
+            {
+                self.queued_tracks.push_back(track);
+                taken += 1;
+            }
+        }
+
+        for artist in similar.artists.and_then(|a| a.items).unwrap_or_default() {
+            if let Some(similar_id) = artist.id
+                && self.visited_artists.insert(similar_id)
+            {
+                self.pending_artists.push_back(similar_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Turns the radio into a stream of tracks.
+    ///
+    /// If an API request fails, the error is yielded and the stream ends.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Track, QobuzApiError>> + 'a {
+        try_unfold(self, |mut radio| async move {
+            Ok(radio.next_track().await?.map(|track| (track, radio)))
+        })
+    }
+}
+
+impl QobuzApiService {
+    /// Creates a radio track feed seeded by an artist.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the seed artist
+    /// * `config` - Diversity and explicit-content settings of the radio
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Radio)` - The radio, ready to produce tracks
+    /// * `Err(QobuzApiError)` - If the artist ID is not a valid numeric ID, or
+    ///   `tracks_per_artist` is 0
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::radio::RadioConfig;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let mut radio = service.artist_radio("12345", RadioConfig::default())?;
+    ///
+    /// for _ in 0..20 {
+    ///     if let Some(track) = radio.next_track().await? {
+    ///         println!("Up next: {:?}", track.title);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn artist_radio(
+        &self,
+        artist_id: &str,
+        config: RadioConfig,
+    ) -> Result<Radio<'_>, QobuzApiError> {
+        config.validate()?;
+        let seed_artist_id = artist_id.parse().map_err(|_| InvalidParameterError {
+            message: format!("Invalid artist ID: {}", artist_id),
+        })?;
+
+        Ok(Radio::new(self, seed_artist_id, config))
+    }
+
+    /// Creates a radio track feed seeded by an album, for autoplay once the album ends.
+    ///
+    /// The radio is seeded by the main artist of the album, and never queues the
+    /// tracks of the album itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the seed album
+    /// * `config` - Diversity and explicit-content settings of the radio
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Radio)` - The radio, ready to produce tracks
+    /// * `Err(QobuzApiError)` - If `tracks_per_artist` is 0, or the album cannot be retrieved
+    ///   or has no main artist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::radio::RadioConfig;
+    /// use futures_util::{StreamExt, TryStreamExt};
+    ///
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let radio = service.album_radio("0886443927087", RadioConfig::default()).await?;
+    /// let next_tracks: Vec<_> = radio.into_stream().take(10).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_radio(
+        &self,
+        album_id: &str,
+        config: RadioConfig,
+    ) -> Result<Radio<'_>, QobuzApiError> {
+        config.validate()?;
+        let album = self
+            .get_album(album_id, None, Some("track_ids"), None, None)
+            .await?;
+
+        let Some(seed_artist_id) = album.artist.as_ref().and_then(|artist| artist.id) else {
+            return Err(ResourceNotFoundError {
+                resource_type: "artist".to_string(),
+                resource_id: album_id.to_string(),
+            });
+        };
+
+        let mut radio = Radio::new(self, seed_artist_id, config);
+        radio.exclude_tracks(album.track_ids.unwrap_or_default());
+        radio.exclude_tracks(
+            album
+                .tracks
+                .and_then(|tracks| tracks.items)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|track| track.id),
+        );
+
+        Ok(radio)
+    }
+}
@@ -0,0 +1,376 @@
+use std::{collections::HashSet, pin::pin};
+
+use futures_util::{StreamExt, TryStreamExt};
+
+use crate::{
+    api::{
+        content::identifiers::{normalize_isrc, normalize_upc},
+        pagination::paginate,
+        service::QobuzApiService,
+    },
+    errors::QobuzApiError::{self},
+    models::{Album, Track},
+};
+
+/// A search query with client-side filters on the returned albums and tracks.
+///
+/// The Qobuz search endpoints only take a text query. A `SearchQuery` pages through
+/// the search results, keeps the items matching every filter that is set, and removes
+/// duplicate editions until enough matches are found.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::{api::content::search_query::SearchQuery, models::Album};
+///
+/// // Hi-res studio albums from the nineties, without explicit content
+/// let query = SearchQuery {
+///     hires_only: true,
+///     min_year: Some(1990),
+///     max_year: Some(1999),
+///     explicit: Some(false),
+///     release_type: Some("album".to_string()),
+///     ..SearchQuery::new("radiohead")
+/// };
+///
+/// let album = Album {
+///     hires: Some(true),
+///     release_date_original: Some("1997-05-21".to_string()),
+///     release_type: Some("album".to_string()),
+///     ..Default::default()
+/// };
+/// assert!(query.matches_album(&album));
+///
+/// let single = Album {
+///     release_type: Some("single".to_string()),
+///     ..album
+/// };
+/// assert!(!query.matches_album(&single));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+    /// The text to search for
+    pub query: String,
+    /// Only keep items available in hi-res. Defaults to `false`.
+    pub hires_only: bool,
+    /// Only keep items originally released in or after this year
+    pub min_year: Option<i32>,
+    /// Only keep items originally released in or before this year
+    pub max_year: Option<i32>,
+    /// Only keep items of the genre with this ID (for tracks, the genre of their album)
+    pub genre_id: Option<i32>,
+    /// Only keep items with (`Some(true)`) or without (`Some(false)`) a parental warning
+    pub explicit: Option<bool>,
+    /// Only keep items that are (`Some(true)`) or are not (`Some(false)`) streamable
+    pub streamable: Option<bool>,
+    /// Only keep items that are (`Some(true)`) or are not (`Some(false)`) downloadable
+    pub downloadable: Option<bool>,
+    /// Only keep items lasting at least this many seconds
+    pub min_duration: Option<i64>,
+    /// Only keep items with this release type, such as "album", "single" or "epmini"
+    /// (for tracks, the release type of their album). Compared case-insensitively.
+    pub release_type: Option<String>,
+    /// Stop once this many matches are found. Defaults to 50.
+    pub max_results: usize,
+    /// The number of search results requested per page. Defaults to 50.
+    pub page_size: i32,
+    /// Stop after examining this many search results, even if fewer matches were found.
+    ///
+    /// This bounds the number of requests made for restrictive filters. Defaults to 500.
+    pub max_scanned: usize,
+    /// Whether to search with or without user authentication token.
+    /// When `None`, defaults to `false` (no authentication).
+    pub with_auth: Option<bool>,
+}
+
+impl SearchQuery {
+    /// Creates a query for the given text, without any filters.
+    pub fn new(query: &str) -> Self {
+        Self {
+            query: query.to_string(),
+            hires_only: false,
+            min_year: None,
+            max_year: None,
+            genre_id: None,
+            explicit: None,
+            streamable: None,
+            downloadable: None,
+            min_duration: None,
+            release_type: None,
+            max_results: 50,
+            page_size: 50,
+            max_scanned: 500,
+            with_auth: None,
+        }
+    }
+
+    /// Returns `true` if the album passes every filter of the query.
+    pub fn matches_album(&self, album: &Album) -> bool {
+        self.matches(&Candidate {
+            hires: album.hires.or(album.hires_streamable),
+            release_date: album
+                .release_date_original
+                .as_deref()
+                .or(album.release_date_stream.as_deref()),
+            genre_id: album.genre.as_ref().and_then(|genre| genre.id),
+            parental_warning: album.parental_warning,
+            streamable: album.streamable,
+            downloadable: album.downloadable,
+            duration: album.duration,
+            release_type: album.release_type.as_deref(),
+        })
+    }
+
+    /// Returns `true` if the track passes every filter of the query.
+    ///
+    /// Genre and release type filters are checked against the album of the track.
+    pub fn matches_track(&self, track: &Track) -> bool {
+        let album = track.album.as_deref();
+
+        self.matches(&Candidate {
+            hires: track.hires.or(track.hires_streamable),
+            release_date: track
+                .release_date_original
+                .as_deref()
+                .or(track.release_date_stream.as_deref())
+                .or(album.and_then(|a| a.release_date_original.as_deref())),
+            genre_id: album.and_then(|a| a.genre.as_ref()).and_then(|g| g.id),
+            parental_warning: track.parental_warning,
+            streamable: track.streamable,
+            downloadable: track.downloadable,
+            duration: track.duration,
+            release_type: album.and_then(|a| a.release_type.as_deref()),
+        })
+    }
+
+    fn matches(&self, candidate: &Candidate<'_>) -> bool {
+        if self.hires_only && candidate.hires != Some(true) {
+            return false;
+        }
+
+        if self.min_year.is_some() || self.max_year.is_some() {
+            // The release dates are formatted as YYYY-MM-DD
+            let Some(year) = candidate
+                .release_date
+                .and_then(|date| date.get(..4))
+                .and_then(|year| year.parse::<i32>().ok())
+            else {
+                return false;
+            };
+
+            if self.min_year.is_some_and(|min| year < min)
+                || self.max_year.is_some_and(|max| year > max)
+            {
+                return false;
+            }
+        }
+
+        if self.genre_id.is_some() && candidate.genre_id != self.genre_id {
+            return false;
+        }
+
+        if let Some(explicit) = self.explicit
+            && candidate.parental_warning.unwrap_or(false) != explicit
+        {
+            return false;
+        }
+
+        if let Some(streamable) = self.streamable
+            && candidate.streamable.unwrap_or(false) != streamable
+        {
+            return false;
+        }
+
+        if let Some(downloadable) = self.downloadable
+            && candidate.downloadable.unwrap_or(false) != downloadable
+        {
+            return false;
+        }
+
+        if let Some(min_duration) = self.min_duration
+            && candidate.duration.unwrap_or(0) < min_duration
+        {
+            return false;
+        }
+
+        if let Some(ref release_type) = self.release_type
+            && !candidate
+                .release_type
+                .is_some_and(|t| t.eq_ignore_ascii_case(release_type))
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// The fields of an album or track checked by the filters of a [`SearchQuery`].
+struct Candidate<'a> {
+    hires: Option<bool>,
+    release_date: Option<&'a str>,
+    genre_id: Option<i32>,
+    parental_warning: Option<bool>,
+    streamable: Option<bool>,
+    downloadable: Option<bool>,
+    duration: Option<i64>,
+    release_type: Option<&'a str>,
+}
+
+impl QobuzApiService {
+    /// Searches for albums and keeps those matching the filters of the query.
+    ///
+    /// Search result pages are requested until `max_results` matches are found,
+    /// `max_scanned` results were examined, or the results run out. Albums sharing a UPC
+    /// are only returned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search text and the filters to apply
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Album>)` - The matching albums, in search result order
+    /// * `Err(QobuzApiError)` - If a search request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::search_query::SearchQuery;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let query = SearchQuery {
+    ///     hires_only: true,
+    ///     downloadable: Some(true),
+    ///     max_results: 20,
+    ///     ..SearchQuery::new("bach cello suites")
+    /// };
+    ///
+    /// for album in service.search_albums_filtered(&query).await? {
+    ///     println!("{:?}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_albums_filtered(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<Album>, QobuzApiError> {
+        let text = query.query.as_str();
+        let with_auth = query.with_auth;
+
+        let mut candidates = pin!(
+            paginate(0, query.page_size, move |limit, offset| async move {
+                let result = self
+                    .search_albums(text, Some(limit), Some(offset), with_auth)
+                    .await?;
+                Ok(result.albums.unwrap_or_default())
+            })
+            .take(query.max_scanned)
+        );
+
+        let mut seen = HashSet::new();
+        let mut albums = Vec::new();
+
+        while albums.len() < query.max_results
+            && let Some(album) = candidates.try_next().await?
+        {
+            if query.matches_album(&album) && album_key(&album).is_none_or(|key| seen.insert(key)) {
+                albums.push(album);
+            }
+        }
+
+        Ok(albums)
+    }
+
+    /// Searches for tracks and keeps those matching the filters of the query.
+    ///
+    /// Search result pages are requested until `max_results` matches are found,
+    /// `max_scanned` results were examined, or the results run out. Tracks sharing an
+    /// ISRC, such as the same recording on a compilation, are only returned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search text and the filters to apply
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Track>)` - The matching tracks, in search result order
+    /// * `Err(QobuzApiError)` - If a search request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::search_query::SearchQuery;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let query = SearchQuery {
+    ///     explicit: Some(false),
+    ///     min_duration: Some(120),
+    ///     ..SearchQuery::new("bohemian rhapsody")
+    /// };
+    ///
+    /// let tracks = service.search_tracks_filtered(&query).await?;
+    /// println!("Found {} tracks", tracks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_tracks_filtered(
+        &self,
+        query: &SearchQuery,
+    ) -> Result<Vec<Track>, QobuzApiError> {
+        let text = query.query.as_str();
+        let with_auth = query.with_auth;
+
+        let mut candidates = pin!(
+            paginate(0, query.page_size, move |limit, offset| async move {
+                let result = self
+                    .search_tracks(text, Some(limit), Some(offset), with_auth)
+                    .await?;
+                Ok(result.tracks.unwrap_or_default())
+            })
+            .take(query.max_scanned)
+        );
+
+        let mut seen = HashSet::new();
+        let mut tracks = Vec::new();
+
+        while tracks.len() < query.max_results
+            && let Some(track) = candidates.try_next().await?
+        {
+            if query.matches_track(&track) && track_key(&track).is_none_or(|key| seen.insert(key)) {
+                tracks.push(track);
+            }
+        }
+
+        Ok(tracks)
+    }
+}
+
+/// Returns the key telling album editions apart: the UPC, or the ID of albums without a
+/// valid UPC.
+///
+/// Albums with neither are never considered duplicates.
+fn album_key(album: &Album) -> Option<String> {
+    match album.upc.as_deref().map(normalize_upc) {
+        Some(upc) if !upc.is_empty() && upc.chars().all(|c| c.is_ascii_digit()) => {
+            Some(format!("upc:{}", upc))
+        }
+        _ => album.id.as_ref().map(|id| format!("id:{}", id)),
+    }
+}
+
+/// Returns the key telling recordings apart: the ISRC, or the ID of tracks without a
+/// valid ISRC.
+///
+/// Tracks with neither are never considered duplicates.
+fn track_key(track: &Track) -> Option<String> {
+    match track.isrc.as_deref().map(normalize_isrc) {
+        Some(isrc) if !isrc.is_empty() && isrc.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            Some(format!("isrc:{}", isrc))
+        }
+        _ => track.id.map(|id| format!("id:{}", id)),
+    }
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use crate::{
+    api::{content::playlists::PlaylistLayout, service::QobuzApiService},
+    download::{
+        DownloadOptions,
+        library::Library,
+        outcome::{DownloadAction, DownloadOutcome},
+        template::TemplateValues,
+    },
+    errors::QobuzApiError,
+};
+
+/// An album or playlist to bring up to date in a library.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SyncItem {
+    /// An album, by ID
+    Album(String),
+    /// The tracks of a playlist, by ID
+    Playlist(String),
+}
+
+/// The result of synchronizing one [`SyncItem`].
+#[derive(Debug)]
+pub struct SyncItemReport {
+    /// The synchronized album or playlist
+    pub item: SyncItem,
+    /// The outcome of each track in album or playlist order, or the error that stopped
+    /// the synchronization of the item
+    pub result: Result<Vec<DownloadOutcome>, QobuzApiError>,
+}
+
+/// The result of [`QobuzApiService::sync_library`], with one report per item.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// The reports, in the order the items were given
+    pub items: Vec<SyncItemReport>,
+}
+
+impl SyncReport {
+    /// Counts the tracks of the synchronized items that went through the given action.
+    pub fn count(&self, action: DownloadAction) -> usize {
+        self.outcomes()
+            .filter(|outcome| outcome.action == action)
+            .count()
+    }
+
+    /// Returns the outcome of every track of the items synchronized successfully.
+    pub fn outcomes(&self) -> impl Iterator<Item = &DownloadOutcome> {
+        self.items
+            .iter()
+            .filter_map(|report| report.result.as_ref().ok())
+            .flatten()
+    }
+
+    /// Returns the reports of the items that failed.
+    pub fn failed(&self) -> impl Iterator<Item = &SyncItemReport> {
+        self.items.iter().filter(|report| report.result.is_err())
+    }
+
+    /// Returns `true` if every item was synchronized.
+    pub fn is_complete(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+impl QobuzApiService {
+    /// Brings a library up to date with a list of albums and playlists.
+    ///
+    /// Each album is downloaded into its folder in the library, named after
+    /// [`DownloadOptions::album_template`], and each playlist track into the folder of its
+    /// album, with an M3U8 playlist file in the library root (see
+    /// [`download_playlist`](Self::download_playlist)). The download goes through the
+    /// library manifest, so tracks already in the library are reused, missing or corrupt
+    /// files are downloaded again, and files are upgraded when a better format than the
+    /// recorded one has become available.
+    ///
+    /// The items are synchronized one after another, and a failed item does not stop the
+    /// others.
+    ///
+    /// # Arguments
+    ///
+    /// * `library` - The library to synchronize, replacing [`DownloadOptions::library`]
+    /// * `items` - The albums and playlists to synchronize
+    /// * `format_id` - The format ID specifying the audio quality (see
+    ///   [`download_album`](Self::download_album))
+    /// * `options` - Download options for the tracks
+    ///
+    /// # Returns
+    ///
+    /// A [`SyncReport`] with the outcome of every item.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::sync::Arc;
+    /// # use qobuz_api_rust::{
+    /// #     QobuzApiService, QobuzApiError,
+    /// #     api::content::sync::SyncItem,
+    /// #     download::{DownloadOptions, library::Library, outcome::DownloadAction},
+    /// # };
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let mut service = QobuzApiService::new().await?;
+    /// service.authenticate_with_env().await?;
+    ///
+    /// let library = Arc::new(Library::open("Music")?);
+    /// let items = [
+    ///     SyncItem::Album("0886443927087".to_string()),
+    ///     SyncItem::Playlist("1234567".to_string()),
+    /// ];
+    /// let report = service
+    ///     .sync_library(library, &items, "6", &DownloadOptions::default())
+    ///     .await;
+    ///
+    /// println!(
+    ///     "{} downloaded, {} reused, {} upgraded",
+    ///     report.count(DownloadAction::Downloaded),
+    ///     report.count(DownloadAction::Reused),
+    ///     report.count(DownloadAction::Upgraded)
+    /// );
+    /// for failed in report.failed() {
+    ///     println!("{:?} failed", failed.item);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync_library(
+        &self,
+        library: Arc<Library>,
+        items: &[SyncItem],
+        format_id: &str,
+        options: &DownloadOptions,
+    ) -> SyncReport {
+        let options = DownloadOptions {
+            library: Some(library.clone()),
+            ..options.clone()
+        };
+
+        let mut report = SyncReport::default();
+        for item in items {
+            let result = match item {
+                SyncItem::Album(album_id) => {
+                    self.sync_album(&library, album_id, format_id, &options)
+                        .await
+                }
+                SyncItem::Playlist(playlist_id) => {
+                    self.sync_playlist(&library, playlist_id, format_id, &options)
+                        .await
+                }
+            };
+
+            report.items.push(SyncItemReport {
+                item: item.clone(),
+                result,
+            });
+        }
+
+        report
+    }
+
+    /// Downloads an album into its folder in the library.
+    async fn sync_album(
+        &self,
+        library: &Library,
+        album_id: &str,
+        format_id: &str,
+        options: &DownloadOptions,
+    ) -> Result<Vec<DownloadOutcome>, QobuzApiError> {
+        let album = self.get_album(album_id, None, None, None, None).await?;
+        let folder = options
+            .album_template
+            .render(&TemplateValues::for_album(&album, format_id));
+        let path = library_path(library, &folder);
+
+        self.download_album(album_id, format_id, &path, options)
+            .await
+    }
+
+    /// Downloads the tracks of a playlist into the folders of their albums in the library,
+    /// with its playlist file in the library root.
+    ///
+    /// The synchronization fails with the error of the first track that failed.
+    async fn sync_playlist(
+        &self,
+        library: &Library,
+        playlist_id: &str,
+        format_id: &str,
+        options: &DownloadOptions,
+    ) -> Result<Vec<DownloadOutcome>, QobuzApiError> {
+        let root = library.root().display().to_string();
+        let download = self
+            .download_playlist(
+                playlist_id,
+                format_id,
+                &root,
+                PlaylistLayout::Albums,
+                options,
+            )
+            .await?;
+
+        download
+            .tracks
+            .into_iter()
+            .map(|(_, result)| result)
+            .collect()
+    }
+}
+
+/// Returns the path of a folder relative to the library root.
+fn library_path(library: &Library, folder: &str) -> String {
+    format!("{}/{}", library.root().display(), folder)
+}
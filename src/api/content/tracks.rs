@@ -1,21 +1,78 @@
 use std::{
-    fs::{File, create_dir_all},
-    io::{BufWriter, Write, stdout},
-    path::Path,
+    fs::{File, OpenOptions, create_dir_all, metadata, read_to_string, remove_file, rename, write},
+    io::{BufWriter, Error as IoError, ErrorKind::NotFound, Write},
+    path::{Path, PathBuf, absolute},
 };
 
-use {reqwest::header::CONTENT_LENGTH, tokio_stream::StreamExt};
+use {
+    reqwest::{
+        Response, StatusCode,
+        header::{
+            CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE,
+        },
+    },
+    serde::{Deserialize, Serialize},
+    serde_json::{from_str, to_string},
+    tokio_stream::StreamExt,
+};
 
 use crate::{
-    api::service::QobuzApiService,
+    api::{requests::AuthPolicy, service::QobuzApiService},
+    download::{
+        DownloadOptions,
+        library::{EntryCheck, Library, ManifestEntry},
+        outcome::{
+            AUDIO_EXTENSIONS, DeliveredFormat, DownloadAction, DownloadOutcome, QualityMatch,
+            extension_for_mime_type,
+        },
+        progress::{AlbumContext, DownloadPhase, ProgressReporter},
+        verify::{Verification, VerificationReport, verify_file},
+    },
     errors::QobuzApiError::{
-        self, ApiErrorResponse, DownloadError, HttpError, MetadataError, ResourceNotFoundError,
+        self, ApiErrorResponse, DownloadError, HttpError, InvalidParameterError, MetadataError,
+        ResourceNotFoundError,
     },
-    metadata::{MetadataConfig, embedder::embed_metadata_in_file},
-    models::{FileUrl, SearchResult, Track},
+    metadata::embedder::embed_metadata_in_file,
+    models::{Album, FileUrl, SearchResult, Track},
     utils::{get_current_timestamp, get_md5_hash},
 };
 
+/// The intended use of a track file URL requested from the getFileUrl endpoint.
+///
+/// Qobuz distinguishes between streaming a track and downloading a purchased copy of it.
+/// Purchased albums and tracks should be requested with [`FileUrlIntent::Download`] so
+/// that the files are delivered exactly as bought.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::content::tracks::FileUrlIntent;
+///
+/// assert_eq!(FileUrlIntent::default(), FileUrlIntent::Stream);
+/// assert_eq!(FileUrlIntent::Download.as_str(), "download");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FileUrlIntent {
+    /// Stream the track, as the Qobuz players do
+    #[default]
+    Stream,
+    /// Download a purchased copy of the track
+    Download,
+    /// Import the track into a local library
+    Import,
+}
+
+impl FileUrlIntent {
+    /// Returns the value of the `intent` parameter for this intent.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Stream => "stream",
+            Self::Download => "download",
+            Self::Import => "import",
+        }
+    }
+}
+
 impl QobuzApiService {
     /// Generates the signature for the getFileUrl endpoint.
     ///
@@ -25,6 +82,7 @@ impl QobuzApiService {
     ///
     /// # Arguments
     /// * `format_id` - The format ID for the desired audio quality
+    /// * `intent` - The intended use of the file URL
     /// * `track_id` - The unique identifier of the track
     /// * `timestamp` - The current timestamp to ensure request freshness
     ///
@@ -33,12 +91,17 @@ impl QobuzApiService {
     fn generate_get_file_url_signature(
         &self,
         format_id: &str,
+        intent: FileUrlIntent,
         track_id: &str,
         timestamp: &str,
     ) -> String {
         let data_to_sign = format!(
-            "trackgetFileUrlformat_id{}intentstreamtrack_id{}{}{}",
-            format_id, track_id, timestamp, self.app_secret
+            "trackgetFileUrlformat_id{}intent{}track_id{}{}{}",
+            format_id,
+            intent.as_str(),
+            track_id,
+            timestamp,
+            self.app_secret
         );
 
         get_md5_hash(&data_to_sign)
@@ -79,9 +142,8 @@ impl QobuzApiService {
     ) -> Result<Track, QobuzApiError> {
         let params = vec![("track_id".to_string(), track_id.to_string())];
 
-        let _use_auth = with_auth.unwrap_or(false);
-
-        self.get("/track/get", &params).await
+        self.get_with_auth("/track/get", &params, AuthPolicy::from(with_auth))
+            .await
     }
 
     /// Retrieves the download URL for a track in a specific audio format.
@@ -123,76 +185,113 @@ impl QobuzApiService {
         track_id: &str,
         format_id: &str,
     ) -> Result<FileUrl, QobuzApiError> {
-        let timestamp = get_current_timestamp();
-        let signature = self.generate_get_file_url_signature(format_id, track_id, &timestamp);
+        self.get_track_file_url_with_intent(track_id, format_id, FileUrlIntent::Stream)
+            .await
+    }
 
-        let params = vec![
-            ("track_id".to_string(), track_id.to_string()),
-            ("format_id".to_string(), format_id.to_string()),
-            ("intent".to_string(), "stream".to_string()),
-            ("request_ts".to_string(), timestamp),
-            ("request_sig".to_string(), signature),
-        ];
+    /// Retrieves the download URL for a track in a specific audio format and for a specific use.
+    ///
+    /// This function behaves like [`get_track_file_url`](Self::get_track_file_url), but allows
+    /// requesting the file URL with an intent other than streaming. Use
+    /// [`FileUrlIntent::Download`] to retrieve purchased albums and tracks exactly as bought.
+    ///
+    /// # Arguments
+    /// * `track_id` - The unique identifier of the track
+    /// * `format_id` - The format ID specifying the audio quality (see [`get_track_file_url`](Self::get_track_file_url))
+    /// * `intent` - The intended use of the file URL
+    ///
+    /// # Returns
+    /// * `Ok(FileUrl)` - Contains the download URL and metadata about the audio file if successful
+    /// * `Err(QobuzApiError)` - If the API request fails, credentials are invalid, or the track/format is unavailable
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::content::tracks::FileUrlIntent;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let service = QobuzApiService::new().await?;
+    /// let file_url = service
+    ///     .get_track_file_url_with_intent("12345", "27", FileUrlIntent::Download)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_track_file_url_with_intent(
+        &self,
+        track_id: &str,
+        format_id: &str,
+        intent: FileUrlIntent,
+    ) -> Result<FileUrl, QobuzApiError> {
+        self.resolve_track_file_url(track_id, format_id, intent, None)
+            .await
+    }
 
-        // This endpoint requires authentication
-        match self.get("/track/getFileUrl", &params).await {
-            Ok(result) => Ok(result),
+    /// Requests the file URL of a track, refreshing the app credentials once if the request
+    /// signature is rejected.
+    ///
+    /// The refresh is reported to `progress`, if given.
+    async fn resolve_track_file_url(
+        &self,
+        track_id: &str,
+        format_id: &str,
+        intent: FileUrlIntent,
+        progress: Option<&ProgressReporter<'_>>,
+    ) -> Result<FileUrl, QobuzApiError> {
+        match self
+            .request_track_file_url(track_id, format_id, intent)
+            .await
+        {
+            // Check if this is the signature error that indicates invalid app credentials
             Err(ApiErrorResponse {
                 code,
                 message,
                 status,
-            }) => {
-                // Check if this is the signature error that indicates invalid app credentials
-                if message.contains("Invalid Request Signature parameter") {
-                    eprintln!(
-                        "Invalid signature detected, attempting to refresh app credentials..."
-                    );
-
-                    // Fetch new credentials
-                    match self.refresh_app_credentials().await {
-                        Ok(new_service) => {
-                            // Retry the request with new credentials
-                            let new_timestamp = get_current_timestamp();
-                            let new_signature = new_service.generate_get_file_url_signature(
-                                format_id,
-                                track_id,
-                                &new_timestamp,
-                            );
-
-                            let new_params = vec![
-                                ("track_id".to_string(), track_id.to_string()),
-                                ("format_id".to_string(), format_id.to_string()),
-                                ("intent".to_string(), "stream".to_string()),
-                                ("request_ts".to_string(), new_timestamp),
-                                ("request_sig".to_string(), new_signature),
-                            ];
-
-                            new_service.get("/track/getFileUrl", &new_params).await
-                        }
-
-                        Err(e) => {
-                            eprintln!("Failed to refresh credentials: {}", e);
-                            Err(ApiErrorResponse {
-                                code,
-                                message,
-                                status,
-                            })
-                        }
+            }) if message.contains("Invalid Request Signature parameter") => {
+                if let Some(progress) = progress {
+                    progress.phase(DownloadPhase::RefreshingCredentials);
+                }
+
+                // Retry the request with new credentials, or return the original error
+                match self.refresh_app_credentials().await {
+                    Ok(new_service) => {
+                        new_service
+                            .request_track_file_url(track_id, format_id, intent)
+                            .await
                     }
-                } else {
-                    // Return the original error if it's not a signature error
-                    Err(ApiErrorResponse {
+                    Err(_) => Err(ApiErrorResponse {
                         code,
                         message,
                         status,
-                    })
+                    }),
                 }
             }
-
-            Err(e) => Err(e),
+            result => result,
         }
     }
 
+    /// Sends a signed file URL request with the current app credentials.
+    async fn request_track_file_url(
+        &self,
+        track_id: &str,
+        format_id: &str,
+        intent: FileUrlIntent,
+    ) -> Result<FileUrl, QobuzApiError> {
+        let timestamp = get_current_timestamp();
+        let signature =
+            self.generate_get_file_url_signature(format_id, intent, track_id, &timestamp);
+
+        let params = vec![
+            ("track_id".to_string(), track_id.to_string()),
+            ("format_id".to_string(), format_id.to_string()),
+            ("intent".to_string(), intent.as_str().to_string()),
+            ("request_ts".to_string(), timestamp),
+            ("request_sig".to_string(), signature),
+        ];
+
+        // This endpoint requires authentication
+        self.get("/track/getFileUrl", &params).await
+    }
+
     /// Searches for tracks based on a text query with optional pagination and authentication.
     ///
     /// This function performs a text-based search across Qobuz's track catalog, allowing users
@@ -239,9 +338,8 @@ impl QobuzApiService {
             ("offset".to_string(), offset.unwrap_or(0).to_string()),
         ];
 
-        let _use_auth = with_auth.unwrap_or(false);
-
-        self.get("/track/search", &params).await
+        self.get_with_auth("/track/search", &params, AuthPolicy::from(with_auth))
+            .await
     }
 
     /// Downloads a track to the specified file path with embedded metadata.
@@ -259,22 +357,38 @@ impl QobuzApiService {
     ///   - `7` for FLAC Hi-Res (24-bit, ≤96kHz)
     ///   - `27` for FLAC Hi-Res (24-bit, >96kHz & ≤192kHz)
     /// * `path` - The file system path where the track should be saved
+    /// * `options` - Download options, including the metadata to embed and the file URL intent
     ///
     /// # Returns
-    /// * `Ok(())` - If the track was successfully downloaded and metadata was embedded
-    /// * `Err(QobuzApiError)` - If the API request fails, download fails, directory creation
-    ///   fails, or metadata embedding fails
+    /// * `Ok(DownloadOutcome)` - If the track was successfully downloaded and metadata was
+    ///   embedded, with the format delivered and how it compares with the requested one
+    /// * `Err(QobuzApiError)` - If the API request fails, download fails, the delivered file is
+    ///   refused by [`DownloadOptions::quality_policy`], directory creation fails, or
+    ///   metadata embedding fails
     ///
     /// # Note
-    /// This function displays download progress in the console. The function will attempt
-    /// to create the target directory if it doesn't exist.
+    /// Download progress is reported to [`DownloadOptions::progress`], which prints it to
+    /// the console by default. The function will attempt to create the target directory
+    /// if it doesn't exist.
+    ///
+    /// The file is first written to `{path}.part` and renamed once complete. If a `.part`
+    /// file is left from an interrupted download of the same file, in the same format, the
+    /// download resumes where it stopped. Otherwise it starts over.
+    ///
+    /// If [`DownloadOptions::library`] is set, a track recorded in the library is reused
+    /// from its recorded path instead of being downloaded, unless its file is missing or
+    /// corrupt, or a better format has become available. Downloaded tracks are recorded.
+    /// An upgraded file replaces the recorded one only if it is saved to the same path,
+    /// extension aside, and only once it is verified and tagged. Otherwise the recorded
+    /// file is kept, as it may belong to another album or playlist folder.
     ///
     /// # Example
     /// ```no_run
-    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError, download::DownloadOptions};
     /// # async fn example() -> Result<(), QobuzApiError> {
     /// let service = QobuzApiService::new().await?;
-    /// service.download_track("12345", "6", "./downloads/track.flac").await?;
+    /// let options = DownloadOptions::default();
+    /// service.download_track("12345", "6", "./downloads/track.flac", &options).await?;
     /// println!("Track downloaded successfully!");
     /// # Ok(())
     /// # }
@@ -284,127 +398,629 @@ impl QobuzApiService {
         track_id: &str,
         format_id: &str,
         path: &str,
-        config: &MetadataConfig,
-    ) -> Result<(), QobuzApiError> {
-        match self.get_track_file_url(track_id, format_id).await {
-            Ok(file_url) => {
-                if let Some(url) = file_url.url {
-                    let response =
-                        self.client
-                            .get(&url)
-                            .send()
-                            .await
-                            .map_err(|e| DownloadError {
-                                message: format!("Failed to initiate download: {}", e),
-                            })?;
+        options: &DownloadOptions,
+    ) -> Result<DownloadOutcome, QobuzApiError> {
+        self.download_track_in_album(track_id, format_id, path, options, None)
+            .await
+    }
 
-                    // Check if the response is successful
-                    if !response.status().is_success() {
-                        return Err(HttpError(response.error_for_status().unwrap_err()));
-                    }
+    /// Downloads a track like [`download_track`](Self::download_track), reporting its
+    /// position in an album download along with its progress.
+    pub(crate) async fn download_track_in_album(
+        &self,
+        track_id: &str,
+        format_id: &str,
+        path: &str,
+        options: &DownloadOptions,
+        album: Option<AlbumContext<'_>>,
+    ) -> Result<DownloadOutcome, QobuzApiError> {
+        let progress = ProgressReporter {
+            observer: options.progress.as_ref(),
+            track_id,
+            path,
+            album,
+        };
 
-                    // Create the directory if it doesn't exist
-                    if let Some(parent) = Path::new(path).parent() {
-                        create_dir_all(parent).map_err(|e| DownloadError {
-                            message: format!("Failed to create directory: {}", e),
-                        })?;
+        // Reuse the file recorded in the library if it is still valid, unless a better
+        // format has become available
+        let mut action = DownloadAction::Downloaded;
+        let mut replaced_path = None;
+        if let Some(library) = &options.library
+            && let Some(entry) = library.entry(track_id)
+        {
+            action = match entry.check(library.root(), options.verification) {
+                EntryCheck::Valid => {
+                    if !self
+                        .better_format_available(&entry, track_id, format_id, options)
+                        .await
+                    {
+                        return reuse_library_file(library, &entry, format_id, options, &progress);
                     }
+                    DownloadAction::Upgraded
+                }
+                EntryCheck::Missing | EntryCheck::Corrupt(_) => DownloadAction::Repaired,
+            };
+            replaced_path = Some(library.path_of(&entry));
+        }
 
-                    // Get the total content length if available
-                    let content_length = response
-                        .headers()
-                        .get(CONTENT_LENGTH)
-                        .and_then(|len| len.to_str().ok())
-                        .and_then(|len| len.parse::<u64>().ok());
-
-                    // Create a file to write the response to
-                    let mut dest =
-                        BufWriter::new(File::create(path).map_err(|e| DownloadError {
-                            message: format!("Failed to create file: {}", e),
-                        })?);
-
-                    // Get the response body as bytes stream
-                    let mut stream = response.bytes_stream();
-
-                    let mut downloaded: u64 = 0;
-
-                    while let Some(chunk_result) = stream.next().await {
-                        let chunk = chunk_result.map_err(|e| DownloadError {
-                            message: format!("Failed to read chunk from response stream: {}", e),
-                        })?;
-                        dest.write_all(&chunk).map_err(|e| DownloadError {
-                            message: format!("Failed to write chunk to file: {}", e),
-                        })?;
-                        downloaded += chunk.len() as u64;
-
-                        // Print progress if we know the total size
-                        if let Some(total) = content_length {
-                            print!(
-                                "\rProgress: {}/{} bytes ({:.2}%)",
-                                downloaded,
-                                total,
-                                (downloaded as f64 / total as f64) * 100.0
-                            );
-                        } else {
-                            print!("\rDownloaded: {} bytes", downloaded);
-                        }
-                        stdout().flush().map_err(|e| DownloadError {
-                            message: format!("Failed to flush stdout: {}", e),
-                        })?;
-                    }
+        // An upgrade replaces the recorded file only if it is saved to the same path,
+        // extension aside. The recorded file is moved aside until the new one is verified
+        // and tagged, so that a failed upgrade keeps it.
+        let backup = match replaced_path {
+            Some(replaced)
+                if action == DownloadAction::Upgraded && is_same_target(&replaced, path) =>
+            {
+                let backup = PathBuf::from(format!("{}.old", replaced.display()));
+                rename(&replaced, &backup).map_err(|e| DownloadError {
+                    message: format!("Failed to move the file being upgraded aside: {}", e),
+                })?;
+                Some((replaced, backup))
+            }
+            _ => None,
+        };
 
-                    // Add a new line after progress display
-                    println!();
+        let result = self
+            .download_and_tag(track_id, format_id, path, options, &progress, action)
+            .await;
 
-                    // Flush the writer to ensure all data is written
-                    dest.flush().map_err(|e| DownloadError {
-                        message: format!("Failed to flush file writer: {}", e),
-                    })?;
+        if let Some((replaced, backup)) = backup {
+            if result.is_ok() {
+                let _ = remove_file(backup);
+            } else {
+                let _ = rename(backup, replaced);
+            }
+        }
 
-                    // After downloading, fetch track, album, and artist details to embed metadata
-                    let track =
-                        self.get_track(track_id, None)
-                            .await
-                            .map_err(|e| DownloadError {
-                                message: format!("Failed to get track details for metadata: {}", e),
-                            })?;
-                    let album = if let Some(ref track_album) = track.album {
-                        track_album.as_ref().clone()
-                    } else {
-                        return Err(ResourceNotFoundError {
-                            resource_type: "album".to_string(),
-                            resource_id: track_id.to_string(),
-                        });
-                    };
+        result
+    }
 
-                    let artist = if let Some(ref track_artist) = track.performer {
-                        track_artist.as_ref().clone()
-                    } else if let Some(album_artist) = &album.artist {
-                        album_artist.as_ref().clone()
-                    } else {
-                        return Err(ResourceNotFoundError {
-                            resource_type: "artist".to_string(),
-                            resource_id: track_id.to_string(),
+    /// Downloads, verifies and tags a track file, and records it in the library, if any.
+    async fn download_and_tag(
+        &self,
+        track_id: &str,
+        format_id: &str,
+        path: &str,
+        options: &DownloadOptions,
+        progress: &ProgressReporter<'_>,
+        action: DownloadAction,
+    ) -> Result<DownloadOutcome, QobuzApiError> {
+        let TransferredFile {
+            path: final_path,
+            expected_size,
+            delivered,
+            quality,
+        } = self
+            .transfer_track_file(track_id, format_id, path, options, progress)
+            .await?;
+        let path = final_path.as_str();
+
+        // After downloading, fetch track, album, and artist details to embed metadata
+        let track = self
+            .get_track(track_id, None)
+            .await
+            .map_err(|e| DownloadError {
+                message: format!("Failed to get track details for metadata: {}", e),
+            })?;
+
+        // Check the file before tagging it, and remove it if it is damaged so that the
+        // next attempt downloads it again
+        if options.verification != Verification::Skip {
+            progress.phase(DownloadPhase::Verifying);
+            // A sample lasts about 30 seconds, whatever the duration of the track
+            let expected_duration = if quality == QualityMatch::Sample {
+                None
+            } else {
+                track.duration
+            };
+            let verified =
+                verify_file(path, expected_size, expected_duration, options.verification)
+                    .and_then(VerificationReport::into_result);
+            if let Err(e) = verified {
+                let _ = remove_file(path);
+                return Err(e);
+            }
+        }
+
+        let mut album = if let Some(ref track_album) = track.album {
+            track_album.as_ref().clone()
+        } else {
+            return Err(ResourceNotFoundError {
+                resource_type: "album".to_string(),
+                resource_id: track_id.to_string(),
+            });
+        };
+
+        // Track numbers restart on every disc, so the track total of a multi-disc album
+        // is the number of tracks on the disc of the track
+        if album.media_count.is_some_and(|count| count > 1) {
+            let disc_track_count = match progress.album.as_ref() {
+                Some(context) => context.disc_track_count,
+                None => self.disc_track_count(&track, &album).await,
+            };
+            if disc_track_count.is_some() {
+                album.tracks_count = disc_track_count;
+            }
+        }
+
+        let artist = if let Some(ref track_artist) = track.performer {
+            track_artist.as_ref().clone()
+        } else if let Some(album_artist) = &album.artist {
+            album_artist.as_ref().clone()
+        } else {
+            return Err(ResourceNotFoundError {
+                resource_type: "artist".to_string(),
+                resource_id: track_id.to_string(),
+            });
+        };
+
+        // Embed metadata in the downloaded file
+        progress.phase(DownloadPhase::Tagging);
+        embed_metadata_in_file(path, &track, &album, &artist, &options.metadata)
+            .await
+            .map_err(|e| MetadataError {
+                source: Box::new(e),
+            })?;
+
+        let outcome = DownloadOutcome {
+            track_id: track_id.to_string(),
+            path: final_path.clone(),
+            requested_format_id: format_id.to_string(),
+            delivered,
+            quality,
+            action,
+        };
+
+        if let Some(library) = &options.library {
+            library.record(&outcome, album.id.as_deref()).await?;
+        }
+
+        progress.completed();
+        Ok(outcome)
+    }
+
+    /// Checks whether a better format than the one recorded in the library is delivered
+    /// for a track now.
+    ///
+    /// Only files in a lower format than requested are checked. If the file URL cannot be
+    /// retrieved, the recorded file is kept.
+    async fn better_format_available(
+        &self,
+        entry: &ManifestEntry,
+        track_id: &str,
+        format_id: &str,
+        options: &DownloadOptions,
+    ) -> bool {
+        if !entry.is_below(format_id) {
+            return false;
+        }
+
+        self.get_track_file_url_with_intent(track_id, format_id, options.intent)
+            .await
+            .is_ok_and(|file_url| entry.is_improved_by(&DeliveredFormat::from(&file_url)))
+    }
+
+    /// Counts the tracks on the disc of a track, or returns `None` if the album cannot be
+    /// retrieved.
+    async fn disc_track_count(&self, track: &Track, album: &Album) -> Option<i32> {
+        let album_id = album.id.as_deref()?;
+        let tracks = self
+            .get_album(album_id, None, None, None, None)
+            .await
+            .ok()?
+            .tracks?
+            .items?;
+
+        let disc = track.media_number.unwrap_or(1);
+        let count = tracks
+            .iter()
+            .filter(|t| t.media_number.unwrap_or(1) == disc)
+            .count();
+        i32::try_from(count).ok().filter(|count| *count > 0)
+    }
+
+    /// Downloads the audio file of a track to `path`, through a `.part` file.
+    ///
+    /// The file is written to `{path}.part` and only renamed to `path` once it is complete.
+    /// An existing `.part` file left by an interrupted download is resumed with a `Range`
+    /// request. If the transfer fails midway, for example because the signed file URL
+    /// expired, it is resumed with a fresh file URL, up to [`MAX_TRANSFER_ATTEMPTS`] times.
+    ///
+    /// The delivered file is checked against [`DownloadOptions::quality_policy`] before it
+    /// is downloaded. Once complete, the file is saved with the extension of the delivered
+    /// format, read from the `Content-Type` of the download or the MIME type of the file
+    /// URL, replacing the extension of `path` if it differs.
+    async fn transfer_track_file(
+        &self,
+        track_id: &str,
+        format_id: &str,
+        path: &str,
+        options: &DownloadOptions,
+        progress: &ProgressReporter<'_>,
+    ) -> Result<TransferredFile, QobuzApiError> {
+        // Create the directory if it doesn't exist
+        if let Some(parent) = Path::new(path).parent() {
+            create_dir_all(parent).map_err(|e| DownloadError {
+                message: format!("Failed to create directory: {}", e),
+            })?;
+        }
+
+        let part_path = format!("{}.part", path);
+        let mut last_error = None;
+
+        for _ in 0..MAX_TRANSFER_ATTEMPTS {
+            // Signed file URLs expire, so every attempt requests a fresh one
+            progress.phase(DownloadPhase::ResolvingUrl);
+            let file_url = self
+                .resolve_track_file_url(track_id, format_id, options.intent, Some(progress))
+                .await?;
+            let Some(ref url) = file_url.url else {
+                return Err(DownloadError {
+                    message: "No download URL found for the track".to_string(),
+                });
+            };
+
+            let delivered = DeliveredFormat::from(&file_url);
+            let quality = options.quality_policy.check(&delivered, format_id)?;
+
+            match self
+                .transfer_part(url, &part_path, format_id, progress)
+                .await
+            {
+                Ok((expected_size, content_type)) => {
+                    // The file server knows best, but may answer with a generic type
+                    let extension = content_type
+                        .as_deref()
+                        .and_then(extension_for_mime_type)
+                        .or_else(|| {
+                            delivered
+                                .mime_type
+                                .as_deref()
+                                .and_then(extension_for_mime_type)
                         });
+                    let final_path = match extension {
+                        Some(extension) => path_with_extension(path, extension),
+                        None => path.to_string(),
                     };
 
-                    // Embed metadata in the downloaded file
-                    println!("Embedding metadata in {}", path);
-                    embed_metadata_in_file(path, &track, &album, &artist, config)
-                        .await
-                        .map_err(|e| MetadataError {
-                            source: Box::new(e),
-                        })?;
-
-                    Ok(())
-                } else {
-                    Err(DownloadError {
-                        message: "No download URL found for the track".to_string(),
-                    })
+                    rename(&part_path, &final_path).map_err(|e| DownloadError {
+                        message: format!("Failed to move completed download into place: {}", e),
+                    })?;
+                    let _ = remove_file(part_info_path(&part_path));
+                    return Ok(TransferredFile {
+                        path: final_path,
+                        expected_size,
+                        delivered,
+                        quality,
+                    });
+                }
+                Err(TransferError::Retryable(e)) => {
+                    progress.phase(DownloadPhase::Retrying(e.to_string()));
+                    last_error = Some(e);
+                }
+                Err(TransferError::Fatal(e)) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| DownloadError {
+            message: "Download did not complete".to_string(),
+        }))
+    }
+
+    /// Downloads the remainder of a file into its `.part` file, resuming from its length.
+    ///
+    /// A `.part` file is only resumed if its [`PartInfo`] shows it holds the same format,
+    /// and the range is requested with `If-Range`, so that the server sends the whole file
+    /// if it changed. Returns the size of the whole file and its `Content-Type`, as
+    /// announced by the server.
+    async fn transfer_part(
+        &self,
+        url: &str,
+        part_path: &str,
+        format_id: &str,
+        progress: &ProgressReporter<'_>,
+    ) -> Result<(Option<u64>, Option<String>), TransferError> {
+        let mut existing = metadata(part_path).map(|m| m.len()).unwrap_or(0);
+        let part_info = PartInfo::load(part_path).filter(|info| info.format_id == format_id);
+
+        // A `.part` file of another format, or of unknown origin, cannot be resumed
+        if existing > 0 && part_info.is_none() {
+            remove_part_file(part_path)?;
+            existing = 0;
+        }
+
+        let mut request = self.client.get(url);
+        if existing > 0 {
+            request = request.header(RANGE, format!("bytes={}-", existing));
+            if let Some(validator) = part_info.as_ref().and_then(|info| info.validator.as_ref()) {
+                request = request.header(IF_RANGE, validator);
+            }
+        }
+
+        let response = request.send().await.map_err(|e| {
+            TransferError::Retryable(DownloadError {
+                message: format!("Failed to initiate download: {}", e),
+            })
+        })?;
+
+        let status = response.status();
+        let content_length = response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|len| len.to_str().ok())
+            .and_then(|len| len.parse::<u64>().ok());
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(str::to_string);
+        // Parse `bytes start-end/total` or `bytes */total`
+        let content_range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|range| range.to_str().ok())
+            .and_then(|range| range.strip_prefix("bytes "))
+            .and_then(|range| range.split_once('/'))
+            .map(|(range, total)| {
+                let start = range.split('-').next().and_then(|s| s.parse::<u64>().ok());
+                (start, total.parse::<u64>().ok())
+            });
+
+        // Weak ETags cannot be used with If-Range
+        let validator = response
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .filter(|etag| !etag.starts_with("W/"))
+            .or_else(|| {
+                response
+                    .headers()
+                    .get(LAST_MODIFIED)
+                    .and_then(|modified| modified.to_str().ok())
+            })
+            .map(str::to_string);
+
+        let (resume_from, total) = match status {
+            StatusCode::PARTIAL_CONTENT => match content_range {
+                Some((Some(start), total))
+                    if start == existing
+                        && part_info.as_ref().and_then(|info| info.total)
+                            == total.or(content_length.map(|len| existing + len)) =>
+                {
+                    (existing, total.or(content_length.map(|len| existing + len)))
+                }
+                _ => {
+                    // The server resumed from an unexpected offset, or another file, start over
+                    remove_part_file(part_path)?;
+                    return Err(TransferError::Retryable(DownloadError {
+                        message: "Server resumed the download at an unexpected offset or with \
+                                  another file"
+                            .to_string(),
+                    }));
+                }
+            },
+
+            StatusCode::RANGE_NOT_SATISFIABLE => {
+                // The `.part` file may already hold the whole file
+                if content_range.and_then(|(_, total)| total) == Some(existing)
+                    && part_info.as_ref().and_then(|info| info.total) == Some(existing)
+                {
+                    return Ok((Some(existing), None));
                 }
+
+                remove_part_file(part_path)?;
+                return Err(TransferError::Retryable(DownloadError {
+                    message: "Partial download does not match the file, starting over".to_string(),
+                }));
+            }
+
+            // The server ignored the range, or there was nothing to resume
+            status if status.is_success() => (0, content_length),
+
+            // Expired signed URLs are rejected, retry with a fresh one
+            StatusCode::FORBIDDEN | StatusCode::GONE => {
+                return Err(TransferError::Retryable(status_error(response)));
+            }
+
+            _ => return Err(TransferError::Fatal(status_error(response))),
+        };
+
+        let file = if resume_from > 0 {
+            OpenOptions::new().append(true).open(part_path)
+        } else {
+            // Record what the new `.part` file holds, so that only the same file resumes it
+            PartInfo {
+                format_id: format_id.to_string(),
+                total,
+                validator,
+            }
+            .save(part_path)?;
+            File::create(part_path)
+        };
+        let mut dest = BufWriter::new(file.map_err(|e| {
+            TransferError::Fatal(DownloadError {
+                message: format!("Failed to create file: {}", e),
+            })
+        })?);
+
+        // Get the response body as bytes stream
+        let mut stream = response.bytes_stream();
+        let mut downloaded = resume_from;
+
+        while let Some(chunk_result) = stream.next().await {
+            let chunk = match chunk_result {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    // Keep what was received, so that the next attempt resumes from there
+                    dest.flush().map_err(write_error)?;
+                    return Err(TransferError::Retryable(DownloadError {
+                        message: format!("Failed to read chunk from response stream: {}", e),
+                    }));
+                }
+            };
+            dest.write_all(&chunk).map_err(write_error)?;
+            downloaded += chunk.len() as u64;
+
+            progress.downloading(downloaded, total);
+        }
+
+        // Flush the writer to ensure all data is written
+        dest.flush().map_err(write_error)?;
+
+        match total {
+            Some(total) if downloaded != total => Err(TransferError::Retryable(DownloadError {
+                message: format!("Download ended after {} of {} bytes", downloaded, total),
+            })),
+            _ => Ok((total, content_type)),
+        }
+    }
+}
+
+/// A track file downloaded by [`QobuzApiService::transfer_track_file`].
+struct TransferredFile {
+    /// The path the file was saved to, with the extension of the delivered format
+    path: String,
+    /// The size of the file announced by the server, if any
+    expected_size: Option<u64>,
+    delivered: DeliveredFormat,
+    quality: QualityMatch,
+}
+
+/// Returns the error for a response whose status cannot be downloaded.
+///
+/// Statuses that are not client or server errors, such as a redirect that was not
+/// followed, are reported as a `DownloadError`.
+fn status_error(response: Response) -> QobuzApiError {
+    let status = response.status();
+    match response.error_for_status() {
+        Err(e) => HttpError(e),
+        Ok(_) => DownloadError {
+            message: format!("Unexpected HTTP status {}", status),
+        },
+    }
+}
+
+/// Returns the outcome of a track whose file recorded in the library is reused.
+///
+/// A file in a lower format than requested is checked against
+/// [`DownloadOptions::quality_policy`], as a new download would be.
+fn reuse_library_file(
+    library: &Library,
+    entry: &ManifestEntry,
+    format_id: &str,
+    options: &DownloadOptions,
+    progress: &ProgressReporter<'_>,
+) -> Result<DownloadOutcome, QobuzApiError> {
+    let quality = if entry.is_below(format_id) {
+        options.quality_policy.check(&entry.delivered, format_id)?
+    } else {
+        QualityMatch::Requested
+    };
+
+    progress.completed();
+    Ok(DownloadOutcome {
+        track_id: entry.track_id.clone(),
+        path: library.path_of(entry).to_string_lossy().into_owned(),
+        requested_format_id: format_id.to_string(),
+        delivered: entry.delivered.clone(),
+        quality,
+        action: DownloadAction::Reused,
+    })
+}
+
+/// Returns `true` if two paths name the same file, ignoring known audio extensions.
+fn is_same_target(replaced: &Path, path: &str) -> bool {
+    let without_extension = |path: &Path| {
+        let path = match path.extension().and_then(|e| e.to_str()) {
+            Some(extension)
+                if AUDIO_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()) =>
+            {
+                path.with_extension("")
             }
+            _ => path.to_path_buf(),
+        };
+        absolute(path).ok()
+    };
 
-            Err(e) => Err(e),
+    without_extension(replaced)
+        .is_some_and(|replaced| Some(replaced) == without_extension(Path::new(path)))
+}
+
+/// Returns `path` with the given extension.
+///
+/// A known audio extension is replaced, while any other is kept and the extension is
+/// appended, as titles such as "01. Intro" look like they have one.
+fn path_with_extension(path: &str, extension: &str) -> String {
+    match Path::new(path).extension().and_then(|e| e.to_str()) {
+        Some(current) if current.eq_ignore_ascii_case(extension) => path.to_string(),
+        Some(current) if AUDIO_EXTENSIONS.contains(&current.to_ascii_lowercase().as_str()) => {
+            format!("{}.{}", &path[..path.len() - current.len() - 1], extension)
         }
+        _ => format!("{}.{}", path, extension),
+    }
+}
+
+/// The number of times the transfer of a track file is attempted, each with a fresh URL.
+const MAX_TRANSFER_ATTEMPTS: usize = 3;
+
+/// The outcome of a failed transfer attempt.
+enum TransferError {
+    /// The transfer can be resumed by another attempt
+    Retryable(QobuzApiError),
+    /// Another attempt would fail the same way
+    Fatal(QobuzApiError),
+}
+
+fn write_error(e: IoError) -> TransferError {
+    TransferError::Fatal(DownloadError {
+        message: format!("Failed to write chunk to file: {}", e),
+    })
+}
+
+/// What a `.part` file holds, saved next to it so that it is only resumed with the same
+/// file.
+#[derive(Debug, Serialize, Deserialize)]
+struct PartInfo {
+    /// The format ID the file was requested in
+    format_id: String,
+    /// The size of the whole file, if announced by the server
+    total: Option<u64>,
+    /// The strong ETag or the Last-Modified date of the file, sent with If-Range
+    validator: Option<String>,
+}
+
+impl PartInfo {
+    /// Loads the information of a `.part` file, or returns `None` if it is missing or
+    /// unreadable.
+    fn load(part_path: &str) -> Option<Self> {
+        from_str(&read_to_string(part_info_path(part_path)).ok()?).ok()
+    }
+
+    fn save(&self, part_path: &str) -> Result<(), TransferError> {
+        let content = to_string(self).map_err(|e| {
+            TransferError::Fatal(InvalidParameterError {
+                message: format!("Failed to serialize partial download information: {}", e),
+            })
+        })?;
+        write(part_info_path(part_path), content).map_err(|e| {
+            TransferError::Fatal(DownloadError {
+                message: format!("Failed to write partial download information: {}", e),
+            })
+        })
+    }
+}
+
+/// Returns the path of the file holding the [`PartInfo`] of a `.part` file.
+fn part_info_path(part_path: &str) -> String {
+    format!("{}.info", part_path)
+}
+
+/// Removes a `.part` file that cannot be resumed, along with its information.
+fn remove_part_file(part_path: &str) -> Result<(), TransferError> {
+    let _ = remove_file(part_info_path(part_path));
+    match remove_file(part_path) {
+        Err(e) if e.kind() != NotFound => Err(TransferError::Fatal(DownloadError {
+            message: format!("Failed to remove partial download: {}", e),
+        })),
+        _ => Ok(()),
     }
 }
@@ -1,9 +1,150 @@
 use crate::{
-    api::service::QobuzApiService,
-    errors::QobuzApiError::{self, QobuzApiInitializationError},
-    models::{QobuzApiStatusResponse, UserFavorites, UserFavoritesIds},
+    api::{requests::AuthPolicy, service::QobuzApiService},
+    errors::QobuzApiError::{
+        self, ApiErrorResponse, InvalidParameterError, QobuzApiInitializationError,
+    },
+    models::{
+        Album, Article, Artist, ItemSearchResult, QobuzApiStatusResponse, Track, UserFavorites,
+        UserFavoritesIds,
+    },
 };
 
+/// Favorites backup and restore.
+///
+/// This module provides versioned JSON snapshots of a user's favorites, their restoration
+/// with an optional dry run, and comparison between snapshots or against the live account.
+pub mod backup;
+
+/// Maximum number of IDs sent in a single favorites update request.
+///
+/// Larger ID lists passed to [`QobuzApiService::add_favorites`] and
+/// [`QobuzApiService::remove_favorites`] are split into batches of this size.
+pub const FAVORITES_BATCH_SIZE: usize = 100;
+
+/// The kinds of content that can be marked as favorite.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::favorites::FavoriteKind;
+///
+/// assert_eq!(FavoriteKind::Albums.as_str(), "albums");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FavoriteKind {
+    /// Favorite tracks
+    Tracks,
+    /// Favorite albums
+    Albums,
+    /// Favorite artists
+    Artists,
+    /// Favorite articles (can be listed, but not added or removed through the API)
+    Articles,
+}
+
+impl FavoriteKind {
+    /// Returns the value of the `type` parameter for this kind of favorite.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tracks => "tracks",
+            Self::Albums => "albums",
+            Self::Artists => "artists",
+            Self::Articles => "articles",
+        }
+    }
+
+    /// Returns the name of the ID list parameter used to add or remove favorites of this kind.
+    fn ids_param(&self) -> Option<&'static str> {
+        match self {
+            Self::Tracks => Some("track_ids"),
+            Self::Albums => Some("album_ids"),
+            Self::Artists => Some("artist_ids"),
+            Self::Articles => None,
+        }
+    }
+}
+
+/// A page of favorites of a single kind.
+///
+/// Returned by [`QobuzApiService::get_user_favorites`], with the variant matching the
+/// requested [`FavoriteKind`].
+#[derive(Debug, Clone)]
+pub enum FavoriteItems {
+    /// A page of favorite tracks
+    Tracks(ItemSearchResult<Track>),
+    /// A page of favorite albums
+    Albums(ItemSearchResult<Album>),
+    /// A page of favorite artists
+    Artists(ItemSearchResult<Artist>),
+    /// A page of favorite articles
+    Articles(ItemSearchResult<Article>),
+}
+
+impl FavoriteItems {
+    /// Returns the kind of the favorites in this page.
+    pub fn kind(&self) -> FavoriteKind {
+        match self {
+            Self::Tracks(_) => FavoriteKind::Tracks,
+            Self::Albums(_) => FavoriteKind::Albums,
+            Self::Artists(_) => FavoriteKind::Artists,
+            Self::Articles(_) => FavoriteKind::Articles,
+        }
+    }
+
+    /// Returns the total number of favorites of this kind, if reported by the API.
+    pub fn total(&self) -> Option<i32> {
+        match self {
+            Self::Tracks(page) => page.total,
+            Self::Albums(page) => page.total,
+            Self::Artists(page) => page.total,
+            Self::Articles(page) => page.total,
+        }
+    }
+
+    /// Returns the number of favorites in this page.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Tracks(page) => page.items.as_ref().map_or(0, Vec::len),
+            Self::Albums(page) => page.items.as_ref().map_or(0, Vec::len),
+            Self::Artists(page) => page.items.as_ref().map_or(0, Vec::len),
+            Self::Articles(page) => page.items.as_ref().map_or(0, Vec::len),
+        }
+    }
+
+    /// Returns `true` if this page contains no favorites.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The outcome of adding or removing a list of favorites, per ID.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::favorites::FavoriteUpdateReport;
+///
+/// let report = FavoriteUpdateReport {
+///     succeeded: vec!["123".to_string(), "456".to_string()],
+///     failed: Vec::new(),
+/// };
+/// assert!(report.is_complete());
+/// ```
+#[derive(Debug, Default)]
+pub struct FavoriteUpdateReport {
+    /// IDs that were updated successfully
+    pub succeeded: Vec<String>,
+    /// IDs that could not be updated, with the corresponding error
+    pub failed: Vec<(String, QobuzApiError)>,
+}
+
+impl FavoriteUpdateReport {
+    /// Returns `true` if every ID was updated successfully.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
 impl QobuzApiService {
     /// Add tracks, albums & artists to the authenticated user's favorites.
     /// At least 1 type of favorite to add is required as parameter.
@@ -60,7 +201,8 @@ impl QobuzApiService {
             });
         }
 
-        self.signed_get("/favorite/create", &params).await
+        self.signed_get_with_auth("/favorite/create", &params, AuthPolicy::Required)
+            .await
     }
 
     /// Removes tracks, albums & artists from the authenticated user's favorites.
@@ -118,7 +260,8 @@ impl QobuzApiService {
             });
         }
 
-        self.signed_get("/favorite/delete", &params).await
+        self.signed_get_with_auth("/favorite/delete", &params, AuthPolicy::Required)
+            .await
     }
 
     /// Gets the IDs of the user favorites for the authenticated user or user with the specified user ID.
@@ -166,62 +309,237 @@ impl QobuzApiService {
         params.push(("limit".to_string(), limit.unwrap_or(5000).to_string()));
         params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
 
-        self.signed_get("/favorite/getUserFavoriteIds", &params)
-            .await
+        self.signed_get_with_auth(
+            "/favorite/getUserFavoriteIds",
+            &params,
+            favorites_auth_policy(user_id),
+        )
+        .await
     }
 
-    /// Gets user favorites of the authenticated user or user with the specified user ID.
+    /// Adds favorites of a single kind to the authenticated user's favorites.
+    ///
+    /// Large ID lists are split into batches of [`FAVORITES_BATCH_SIZE`] IDs. If a batch is
+    /// rejected by the API, its IDs are retried one by one, so that the report tells exactly
+    /// which IDs could not be added. Any other error, such as a network error, stops the
+    /// update; the batches sent before it are added nonetheless.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of the favorites to add. Articles cannot be added.
+    /// * `ids` - IDs of the tracks, albums or artists to add
+    ///
+    /// # Returns
+    /// * `Ok(FavoriteUpdateReport)` - The IDs that were added and the IDs that failed
+    /// * `Err(QobuzApiError)` - If no IDs are given, the kind cannot be added, or a batch
+    ///   fails for another reason than being rejected by the API
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::QobuzApiService;
+    /// # use qobuz_api_rust::api::favorites::FavoriteKind;
+    /// # use tokio;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let report = service
+    ///     .add_favorites(FavoriteKind::Albums, &["0886443927087", "0825646068787"])
+    ///     .await?;
+    /// for (id, error) in &report.failed {
+    ///     println!("Could not add {}: {}", id, error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorites(
+        &self,
+        kind: FavoriteKind,
+        ids: &[&str],
+    ) -> Result<FavoriteUpdateReport, QobuzApiError> {
+        self.update_favorites("/favorite/create", kind, ids).await
+    }
+
+    /// Removes favorites of a single kind from the authenticated user's favorites.
+    ///
+    /// Large ID lists are split into batches of [`FAVORITES_BATCH_SIZE`] IDs. If a batch is
+    /// rejected by the API, its IDs are retried one by one, so that the report tells exactly
+    /// which IDs could not be removed. Any other error, such as a network error, stops the
+    /// update; the batches sent before it are removed nonetheless.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of the favorites to remove. Articles cannot be removed.
+    /// * `ids` - IDs of the tracks, albums or artists to remove
+    ///
+    /// # Returns
+    /// * `Ok(FavoriteUpdateReport)` - The IDs that were removed and the IDs that failed
+    /// * `Err(QobuzApiError)` - If no IDs are given, the kind cannot be removed, or a batch
+    ///   fails for another reason than being rejected by the API
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::QobuzApiService;
+    /// # use qobuz_api_rust::api::favorites::FavoriteKind;
+    /// # use tokio;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let report = service
+    ///     .remove_favorites(FavoriteKind::Tracks, &["40128300"])
+    ///     .await?;
+    /// assert!(report.is_complete());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorites(
+        &self,
+        kind: FavoriteKind,
+        ids: &[&str],
+    ) -> Result<FavoriteUpdateReport, QobuzApiError> {
+        self.update_favorites("/favorite/delete", kind, ids).await
+    }
+
+    /// Sends favorites updates in batches and collects the outcome per ID.
+    async fn update_favorites(
+        &self,
+        endpoint: &str,
+        kind: FavoriteKind,
+        ids: &[&str],
+    ) -> Result<FavoriteUpdateReport, QobuzApiError> {
+        let Some(ids_param) = kind.ids_param() else {
+            return Err(InvalidParameterError {
+                message: format!("Favorites of type '{}' cannot be updated", kind.as_str()),
+            });
+        };
+
+        if ids.is_empty() {
+            return Err(InvalidParameterError {
+                message: "At least one favorite ID must be provided".to_string(),
+            });
+        }
+
+        // Fail fast rather than reporting every ID as failed
+        self.auth_token_for(AuthPolicy::Required)?;
+
+        let mut report = FavoriteUpdateReport::default();
+
+        for batch in ids.chunks(FAVORITES_BATCH_SIZE) {
+            let params = vec![(ids_param.to_string(), batch.join(","))];
+
+            match self
+                .signed_get_with_auth::<QobuzApiStatusResponse>(
+                    endpoint,
+                    &params,
+                    AuthPolicy::Required,
+                )
+                .await
+            {
+                Ok(_) => {
+                    report
+                        .succeeded
+                        .extend(batch.iter().map(|id| id.to_string()));
+                    continue;
+                }
+                Err(ApiErrorResponse { .. }) => {}
+                // Retrying the IDs one by one would only repeat the same error
+                Err(e) => return Err(e),
+            }
+
+            // The batch was rejected, find out which IDs are affected
+            for id in batch {
+                let params = vec![(ids_param.to_string(), id.to_string())];
+
+                match self
+                    .signed_get_with_auth::<QobuzApiStatusResponse>(
+                        endpoint,
+                        &params,
+                        AuthPolicy::Required,
+                    )
+                    .await
+                {
+                    Ok(_) => report.succeeded.push(id.to_string()),
+                    Err(e) => report.failed.push((id.to_string(), e)),
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Gets a page of user favorites of a single kind, for the authenticated user or user with the specified user ID.
     ///
     /// # Arguments
     /// * `user_id` - The User ID to fetch the favorites from. If omitted, returns favorites of the logged in user using user_auth_token (optional)
-    /// * `type_param` - Type of favorites to include in the response (optional). Possible values are 'tracks', 'albums', 'artists' & 'articles'. If no type defined, all types are returned
+    /// * `kind` - The kind of favorites to retrieve
     /// * `limit` - The maximum number of extra results to return. Defaults to 50, minimum 1, maximum 500 (optional)
     /// * `offset` - The offset of the first extra result to return. Defaults to 0 (optional)
     ///
     /// # Returns
-    /// * `Ok(UserFavorites)` - The user favorites
+    /// * `Ok(FavoriteItems)` - A page of favorites, with the variant matching `kind`
     /// * `Err(QobuzApiError)` - If the API request fails
     ///
     /// # Example
     /// ```rust,no_run
     /// # use qobuz_api_rust::QobuzApiService;
+    /// # use qobuz_api_rust::api::favorites::{FavoriteItems, FavoriteKind};
     /// # use tokio;
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// # let service = QobuzApiService::new().await?;
-    /// // Get all favorite types with default parameters
-    /// let favorites = service.get_user_favorites(None, None, None, None).await?;
+    /// let favorites = service
+    ///     .get_user_favorites(None, FavoriteKind::Albums, Some(100), None)
+    ///     .await?;
     ///
-    /// // Get only favorite albums with custom parameters
-    /// let album_favorites = service.get_user_favorites(
-    ///     None,
-    ///     Some("albums"),
-    ///     Some(100),
-    ///     Some(20),
-    /// ).await?;
+    /// if let FavoriteItems::Albums(albums) = favorites {
+    ///     for album in albums.items.unwrap_or_default() {
+    ///         println!("{:?}", album.title);
+    ///     }
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_user_favorites(
         &self,
         user_id: Option<&str>,
-        type_param: Option<&str>,
+        kind: FavoriteKind,
         limit: Option<i32>,
         offset: Option<i32>,
-    ) -> Result<UserFavorites, QobuzApiError> {
+    ) -> Result<FavoriteItems, QobuzApiError> {
         let mut params = Vec::new();
 
         if let Some(id) = user_id {
             params.push(("user_id".to_string(), id.to_string()));
         }
 
-        if let Some(t) = type_param {
-            params.push(("type".to_string(), t.to_string()));
-        }
-
+        params.push(("type".to_string(), kind.as_str().to_string()));
         params.push(("limit".to_string(), limit.unwrap_or(50).to_string()));
         params.push(("offset".to_string(), offset.unwrap_or(0).to_string()));
 
-        self.signed_get("/favorite/getUserFavorites", &params).await
+        let favorites: UserFavorites = self
+            .signed_get_with_auth(
+                "/favorite/getUserFavorites",
+                &params,
+                favorites_auth_policy(user_id),
+            )
+            .await?;
+
+        Ok(match kind {
+            FavoriteKind::Tracks => FavoriteItems::Tracks(favorites.tracks.unwrap_or_default()),
+            FavoriteKind::Albums => FavoriteItems::Albums(favorites.albums.unwrap_or_default()),
+            FavoriteKind::Artists => FavoriteItems::Artists(favorites.artists.unwrap_or_default()),
+            FavoriteKind::Articles => {
+                FavoriteItems::Articles(favorites.articles.unwrap_or_default())
+            }
+        })
+    }
+}
+
+/// Returns the auth policy for reading favorites.
+///
+/// The favorites of the authenticated user require the token, while the public favorites
+/// of another user can be read anonymously.
+fn favorites_auth_policy(user_id: Option<&str>) -> AuthPolicy {
+    if user_id.is_some() {
+        AuthPolicy::IfAvailable
+    } else {
+        AuthPolicy::Required
     }
 }
@@ -0,0 +1,476 @@
+use std::{
+    collections::BTreeSet,
+    fs::{read_to_string, rename, write},
+    path::Path,
+};
+
+use {
+    futures_util::TryStreamExt,
+    serde::{Deserialize, Serialize},
+    serde_json::{from_str, to_string_pretty},
+};
+
+use crate::{
+    api::{
+        favorites::{FavoriteItems, FavoriteKind, FavoriteUpdateReport},
+        pagination::paginate,
+        service::QobuzApiService,
+    },
+    errors::QobuzApiError::{self, InvalidParameterError, IoError},
+    models::{ItemSearchResult, Playlist},
+    utils::get_current_timestamp,
+};
+
+/// Version of the favorites snapshot format written by this library.
+pub const FAVORITES_SNAPSHOT_VERSION: u32 = 1;
+
+/// A point-in-time copy of a user's favorites.
+///
+/// Snapshots are created with [`QobuzApiService::export_favorites`], stored as versioned
+/// JSON files, and restored with [`QobuzApiService::import_favorites`].
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::favorites::backup::FavoritesSnapshot;
+///
+/// # fn main() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let snapshot = FavoritesSnapshot {
+///     albums: vec!["0886443927087".to_string()],
+///     tracks: vec!["40128300".to_string()],
+///     ..Default::default()
+/// };
+///
+/// let path = std::env::temp_dir().join("qobuz_favorites_snapshot_doctest.json");
+/// snapshot.save(&path)?;
+///
+/// let restored = FavoritesSnapshot::load(&path)?;
+/// assert_eq!(restored.albums, snapshot.albums);
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FavoritesSnapshot {
+    /// Version of the snapshot format
+    pub version: u32,
+    /// Unix timestamp of when the snapshot was taken
+    pub created_at: i64,
+    /// ID of the user the snapshot was taken from, if known
+    pub user_id: Option<String>,
+    /// IDs of the favorite tracks
+    pub tracks: Vec<String>,
+    /// IDs of the favorite albums
+    pub albums: Vec<String>,
+    /// IDs of the favorite artists
+    pub artists: Vec<String>,
+    /// IDs of the playlists the user is subscribed to.
+    ///
+    /// Snapshots taken before owned playlists were recorded separately may also list
+    /// the playlists owned by the user here.
+    pub playlists: Vec<String>,
+    /// IDs of the playlists owned by the user
+    #[serde(default)]
+    pub owned_playlists: Vec<String>,
+}
+
+impl Default for FavoritesSnapshot {
+    fn default() -> Self {
+        Self {
+            version: FAVORITES_SNAPSHOT_VERSION,
+            created_at: get_current_timestamp().parse().unwrap_or_default(),
+            user_id: None,
+            tracks: Vec::new(),
+            albums: Vec::new(),
+            artists: Vec::new(),
+            playlists: Vec::new(),
+            owned_playlists: Vec::new(),
+        }
+    }
+}
+
+impl FavoritesSnapshot {
+    /// Loads a snapshot from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IoError` if the file cannot be read or parsed, and `InvalidParameterError`
+    /// if the snapshot was written by a newer, unsupported version of the format.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, QobuzApiError> {
+        let content = read_to_string(path).map_err(IoError)?;
+        let snapshot: Self = from_str(&content).map_err(|e| IoError(e.into()))?;
+
+        if snapshot.version > FAVORITES_SNAPSHOT_VERSION {
+            return Err(InvalidParameterError {
+                message: format!(
+                    "Unsupported favorites snapshot version {} (latest supported is {})",
+                    snapshot.version, FAVORITES_SNAPSHOT_VERSION
+                ),
+            });
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Writes the snapshot to a JSON file.
+    ///
+    /// The snapshot is written to a temporary file in the same folder first, then renamed
+    /// over `path`, so that an interrupted save leaves the previous snapshot intact.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), QobuzApiError> {
+        let path = path.as_ref();
+        let content = to_string_pretty(self).map_err(|e| IoError(e.into()))?;
+
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        write(&temp_path, content).map_err(IoError)?;
+        rename(&temp_path, path).map_err(IoError)
+    }
+
+    /// Compares this snapshot with a newer one.
+    ///
+    /// IDs present in `other` but not in `self` are reported as added, IDs present in
+    /// `self` but not in `other` as removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qobuz_api_rust::api::favorites::backup::FavoritesSnapshot;
+    ///
+    /// let before = FavoritesSnapshot {
+    ///     tracks: vec!["1".to_string(), "2".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// let after = FavoritesSnapshot {
+    ///     tracks: vec!["2".to_string(), "3".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.tracks.added, vec!["3".to_string()]);
+    /// assert_eq!(diff.tracks.removed, vec!["1".to_string()]);
+    /// assert!(diff.albums.is_empty());
+    /// ```
+    pub fn diff(&self, other: &FavoritesSnapshot) -> FavoritesDiff {
+        FavoritesDiff {
+            tracks: IdDiff::between(&self.tracks, &other.tracks),
+            albums: IdDiff::between(&self.albums, &other.albums),
+            artists: IdDiff::between(&self.artists, &other.artists),
+            playlists: IdDiff::between(&self.playlists, &other.playlists),
+            owned_playlists: IdDiff::between(&self.owned_playlists, &other.owned_playlists),
+        }
+    }
+}
+
+/// The IDs added and removed between two lists of favorites.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdDiff {
+    /// IDs present only in the newer list
+    pub added: Vec<String>,
+    /// IDs present only in the older list
+    pub removed: Vec<String>,
+}
+
+impl IdDiff {
+    fn between(old: &[String], new: &[String]) -> Self {
+        let old_ids: BTreeSet<&String> = old.iter().collect();
+        let new_ids: BTreeSet<&String> = new.iter().collect();
+
+        Self {
+            added: new_ids
+                .difference(&old_ids)
+                .map(|id| id.to_string())
+                .collect(),
+            removed: old_ids
+                .difference(&new_ids)
+                .map(|id| id.to_string())
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if both lists contain the same IDs.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// The differences between two favorites snapshots, per kind of favorite.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FavoritesDiff {
+    /// Added and removed tracks
+    pub tracks: IdDiff,
+    /// Added and removed albums
+    pub albums: IdDiff,
+    /// Added and removed artists
+    pub artists: IdDiff,
+    /// Added and removed playlist subscriptions
+    pub playlists: IdDiff,
+    /// Added and removed owned playlists
+    pub owned_playlists: IdDiff,
+}
+
+impl FavoritesDiff {
+    /// Returns `true` if both snapshots contain the same favorites.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+            && self.albums.is_empty()
+            && self.artists.is_empty()
+            && self.playlists.is_empty()
+            && self.owned_playlists.is_empty()
+    }
+}
+
+/// The outcome of restoring a favorites snapshot.
+#[derive(Debug, Default)]
+pub struct FavoritesImportReport {
+    /// Whether the import was a dry run, in which case nothing was changed
+    pub dry_run: bool,
+    /// The differences between the live account and the snapshot before the import.
+    ///
+    /// Only the `added` IDs are restored; favorites missing from the snapshot are kept.
+    /// Owned playlists are never restored, and playlists owned by the user are left out
+    /// of the planned subscriptions.
+    pub planned: FavoritesDiff,
+    /// Outcome of restoring the favorite tracks
+    pub tracks: FavoriteUpdateReport,
+    /// Outcome of restoring the favorite albums
+    pub albums: FavoriteUpdateReport,
+    /// Outcome of restoring the favorite artists
+    pub artists: FavoriteUpdateReport,
+    /// Outcome of subscribing to the playlists
+    pub playlists: FavoriteUpdateReport,
+}
+
+impl QobuzApiService {
+    /// Exports the favorites of the authenticated user, or of the user with the specified ID.
+    ///
+    /// The snapshot contains the IDs of the favorite tracks, albums and artists. Playlists
+    /// are only included for the authenticated user, since the API does not list the
+    /// playlists of other users, and are split into the playlists owned by the user and
+    /// the playlists the user is subscribed to.
+    ///
+    /// # Arguments
+    /// * `user_id` - The User ID to export the favorites of. If omitted, exports the favorites of the logged in user (optional)
+    ///
+    /// # Returns
+    /// * `Ok(FavoritesSnapshot)` - The snapshot of the user's favorites
+    /// * `Err(QobuzApiError)` - If an API request fails
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::QobuzApiService;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut service = QobuzApiService::new().await?;
+    /// service.authenticate_with_env().await?;
+    ///
+    /// let snapshot = service.export_favorites(None).await?;
+    /// snapshot.save("favorites.json")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_favorites(
+        &self,
+        user_id: Option<&str>,
+    ) -> Result<FavoritesSnapshot, QobuzApiError> {
+        let tracks = self.favorite_ids(user_id, FavoriteKind::Tracks).await?;
+        let albums = self.favorite_ids(user_id, FavoriteKind::Albums).await?;
+        let artists = self.favorite_ids(user_id, FavoriteKind::Artists).await?;
+
+        let mut playlists = Vec::new();
+        let mut owned_playlists = Vec::new();
+        if user_id.is_none() {
+            let user_playlists: Vec<(Playlist, bool)> =
+                paginate(0, 500, |limit, offset| async move {
+                    let user_playlists = self.get_user_playlists(Some(limit), Some(offset)).await?;
+                    let account_id = user_playlists.user.and_then(|user| user.id);
+
+                    let page = user_playlists.playlists.unwrap_or_default();
+
+                    // The listing mixes owned and subscribed playlists
+                    Ok(map_page(page, |playlist| {
+                        let owner_id = playlist.owner.as_ref().and_then(|owner| owner.id);
+                        let owned = account_id.is_some() && owner_id == account_id;
+                        (playlist, owned)
+                    }))
+                })
+                .try_collect()
+                .await?;
+
+            for (playlist, owned) in user_playlists {
+                let Some(id) = playlist.id else {
+                    continue;
+                };
+
+                if owned {
+                    owned_playlists.push(id.to_string());
+                } else {
+                    playlists.push(id.to_string());
+                }
+            }
+        }
+
+        Ok(FavoritesSnapshot {
+            user_id: user_id.map(str::to_string),
+            tracks,
+            albums,
+            artists,
+            playlists,
+            owned_playlists,
+            ..Default::default()
+        })
+    }
+
+    /// Compares a snapshot with the live favorites of the authenticated user.
+    ///
+    /// IDs present in the live account but not in the snapshot are reported as added,
+    /// IDs present in the snapshot but no longer in the live account as removed.
+    ///
+    /// # Arguments
+    /// * `snapshot` - The snapshot to compare with the live account
+    ///
+    /// # Returns
+    /// * `Ok(FavoritesDiff)` - The changes since the snapshot was taken
+    /// * `Err(QobuzApiError)` - If an API request fails
+    pub async fn diff_favorites(
+        &self,
+        snapshot: &FavoritesSnapshot,
+    ) -> Result<FavoritesDiff, QobuzApiError> {
+        let live = self.export_favorites(None).await?;
+
+        Ok(snapshot.diff(&live))
+    }
+
+    /// Restores a favorites snapshot into the account of the authenticated user.
+    ///
+    /// Favorites present in the snapshot but missing from the account are added, and
+    /// playlists are subscribed to. Favorites that are in the account but not in the
+    /// snapshot are left untouched. Owned playlists are not restored, since a deleted
+    /// playlist cannot be recreated from its ID, and the user cannot subscribe to their own
+    /// playlists. With `dry_run`, the changes are computed and
+    /// reported, but not applied.
+    ///
+    /// # Arguments
+    /// * `snapshot` - The snapshot to restore
+    /// * `dry_run` - Whether to only report the planned changes
+    ///
+    /// # Returns
+    /// * `Ok(FavoritesImportReport)` - The planned changes and, unless dry running, their outcome per ID
+    /// * `Err(QobuzApiError)` - If the live favorites cannot be retrieved
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use qobuz_api_rust::QobuzApiService;
+    /// # use qobuz_api_rust::api::favorites::backup::FavoritesSnapshot;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let snapshot = FavoritesSnapshot::load("favorites.json")?;
+    ///
+    /// let preview = service.import_favorites(&snapshot, true).await?;
+    /// println!("Would restore {} albums", preview.planned.albums.added.len());
+    ///
+    /// let report = service.import_favorites(&snapshot, false).await?;
+    /// println!("Failed albums: {:?}", report.albums.failed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_favorites(
+        &self,
+        snapshot: &FavoritesSnapshot,
+        dry_run: bool,
+    ) -> Result<FavoritesImportReport, QobuzApiError> {
+        let live = self.export_favorites(None).await?;
+        let mut planned = live.diff(snapshot);
+
+        // Older snapshots list owned playlists with the subscriptions
+        planned
+            .playlists
+            .added
+            .retain(|id| !live.owned_playlists.contains(id));
+
+        let mut report = FavoritesImportReport {
+            dry_run,
+            ..Default::default()
+        };
+
+        if !dry_run {
+            report.tracks = self
+                .restore_favorites(FavoriteKind::Tracks, &planned.tracks.added)
+                .await?;
+            report.albums = self
+                .restore_favorites(FavoriteKind::Albums, &planned.albums.added)
+                .await?;
+            report.artists = self
+                .restore_favorites(FavoriteKind::Artists, &planned.artists.added)
+                .await?;
+
+            for playlist_id in &planned.playlists.added {
+                match self.subscribe_playlist(playlist_id).await {
+                    Ok(_) => report.playlists.succeeded.push(playlist_id.clone()),
+                    Err(e) => report.playlists.failed.push((playlist_id.clone(), e)),
+                }
+            }
+        }
+
+        report.planned = planned;
+
+        Ok(report)
+    }
+
+    /// Lists the IDs of every favorite of a kind, page by page.
+    async fn favorite_ids(
+        &self,
+        user_id: Option<&str>,
+        kind: FavoriteKind,
+    ) -> Result<Vec<String>, QobuzApiError> {
+        paginate(0, 500, |limit, offset| async move {
+            let favorites = self
+                .get_user_favorites(user_id, kind, Some(limit), Some(offset))
+                .await?;
+
+            Ok(match favorites {
+                FavoriteItems::Tracks(page) => {
+                    map_page(page, |track| track.id.map(|id| id.to_string()))
+                }
+                FavoriteItems::Albums(page) => map_page(page, |album| album.id),
+                FavoriteItems::Artists(page) => {
+                    map_page(page, |artist| artist.id.map(|id| id.to_string()))
+                }
+                FavoriteItems::Articles(page) => {
+                    map_page(page, |article| article.id.map(|id| id.to_string()))
+                }
+            })
+        })
+        .try_filter_map(|id| async move { Ok(id) })
+        .try_collect()
+        .await
+    }
+
+    /// Adds the given favorites, skipping the request if there is nothing to add.
+    async fn restore_favorites(
+        &self,
+        kind: FavoriteKind,
+        ids: &[String],
+    ) -> Result<FavoriteUpdateReport, QobuzApiError> {
+        if ids.is_empty() {
+            return Ok(FavoriteUpdateReport::default());
+        }
+
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+        self.add_favorites(kind, &ids).await
+    }
+}
+
+/// Converts the items of a page, keeping its pagination details.
+fn map_page<T, U>(page: ItemSearchResult<T>, convert: impl FnMut(T) -> U) -> ItemSearchResult<U> {
+    ItemSearchResult {
+        items: page
+            .items
+            .map(|items| items.into_iter().map(convert).collect()),
+        total: page.total,
+        limit: page.limit,
+        offset: page.offset,
+        has_more: page.has_more,
+    }
+}
@@ -0,0 +1,91 @@
+use std::future::Future;
+
+use futures_util::{
+    Stream, TryStreamExt,
+    stream::{iter, try_unfold},
+};
+
+use crate::{errors::QobuzApiError, models::ItemSearchResult};
+
+/// Turns a paginated endpoint into a stream of its individual items.
+///
+/// The `fetch` closure is called with the page size (`limit`) and the offset of the
+/// page to retrieve, and must return the corresponding page of results. Pages are
+/// requested lazily, one at a time, as the stream is consumed.
+///
+/// The stream ends when the API reports that no more items are available. If the
+/// response does not contain a `has_more` flag, the `total` count is used instead,
+/// and if that is missing too, a page shorter than `page_size` marks the end.
+///
+/// # Arguments
+///
+/// * `start_offset` - The offset of the first item to retrieve
+/// * `page_size` - The number of items to request per page
+/// * `fetch` - A closure retrieving the page for the given limit and offset
+///
+/// # Returns
+///
+/// A stream yielding every item across all pages. If a page request fails, the error
+/// is yielded and the stream ends.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::TryStreamExt;
+/// use qobuz_api_rust::{api::pagination::paginate, models::ItemSearchResult};
+///
+/// # async fn example() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// // Serve 5 numbers in pages of 2
+/// let items: Vec<i32> = paginate(0, 2, |limit, offset| async move {
+///     let items: Vec<i32> = (offset..(offset + limit).min(5)).collect();
+///     Ok(ItemSearchResult {
+///         items: Some(items),
+///         total: Some(5),
+///         limit: Some(limit),
+///         offset: Some(offset),
+///         has_more: None,
+///     })
+/// })
+/// .try_collect()
+/// .await?;
+///
+/// assert_eq!(items, vec![0, 1, 2, 3, 4]);
+/// # Ok(())
+/// # }
+/// # tokio::runtime::Runtime::new().unwrap().block_on(example()).unwrap();
+/// ```
+pub fn paginate<T, F, Fut>(
+    start_offset: i32,
+    page_size: i32,
+    mut fetch: F,
+) -> impl Stream<Item = Result<T, QobuzApiError>>
+where
+    F: FnMut(i32, i32) -> Fut,
+    Fut: Future<Output = Result<ItemSearchResult<T>, QobuzApiError>>,
+{
+    try_unfold(Some(start_offset), move |next_offset| {
+        let request = next_offset.map(|offset| (offset, fetch(page_size, offset)));
+
+        async move {
+            let Some((offset, request)) = request else {
+                return Ok(None);
+            };
+
+            let page = request.await?;
+            let items = page.items.unwrap_or_default();
+            let fetched = offset + items.len() as i32;
+
+            // Prefer the explicit flag, then the total count, then the page length
+            let has_more = !items.is_empty()
+                && match (page.has_more, page.total) {
+                    (Some(has_more), _) => has_more,
+                    (None, Some(total)) => fetched < total,
+                    (None, None) => items.len() as i32 >= page_size,
+                };
+
+            Ok::<_, QobuzApiError>(Some((items, has_more.then_some(fetched))))
+        }
+    })
+    .map_ok(|items| iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
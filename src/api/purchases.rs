@@ -0,0 +1,136 @@
+use futures_util::Stream;
+
+use crate::{
+    api::{pagination::paginate, requests::AuthPolicy, service::QobuzApiService},
+    errors::QobuzApiError::{self},
+    models::{Album, Track, UserPurchases},
+};
+
+/// The kind of purchases to list.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::purchases::PurchaseType;
+///
+/// assert_eq!(PurchaseType::Albums.as_str(), "albums");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseType {
+    /// Purchased albums
+    Albums,
+    /// Individually purchased tracks
+    Tracks,
+}
+
+impl PurchaseType {
+    /// Returns the value of the `type` parameter for this kind of purchase.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Albums => "albums",
+            Self::Tracks => "tracks",
+        }
+    }
+}
+
+impl QobuzApiService {
+    /// Lists the albums or tracks purchased by the authenticated user.
+    ///
+    /// # Arguments
+    /// * `purchase_type` - Whether to list purchased albums or tracks
+    /// * `limit` - The maximum number of purchases to return (optional, defaults to 50)
+    /// * `offset` - The offset of the first purchase to return (optional, defaults to 0)
+    ///
+    /// # Returns
+    /// * `Ok(UserPurchases)` - A page of the user's purchased albums or tracks
+    /// * `Err(QobuzApiError)` - If the user is not authenticated or the API request fails
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::purchases::PurchaseType;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let mut service = QobuzApiService::new().await?;
+    /// service.authenticate_with_env().await?;
+    ///
+    /// let purchases = service
+    ///     .get_user_purchases(PurchaseType::Albums, Some(100), None)
+    ///     .await?;
+    /// for album in purchases.albums.and_then(|a| a.items).unwrap_or_default() {
+    ///     println!("{:?}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_purchases(
+        &self,
+        purchase_type: PurchaseType,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<UserPurchases, QobuzApiError> {
+        let params = vec![
+            ("type".to_string(), purchase_type.as_str().to_string()),
+            ("limit".to_string(), limit.unwrap_or(50).to_string()),
+            ("offset".to_string(), offset.unwrap_or(0).to_string()),
+        ];
+
+        self.get_with_auth("/purchase/getUserPurchases", &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Streams every album purchased by the authenticated user.
+    ///
+    /// Further pages are requested from the API only as the stream is consumed.
+    ///
+    /// # Returns
+    /// A stream yielding each purchased `Album`. If a page request fails, the error is
+    /// yielded and the stream ends.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::{api::content::tracks::FileUrlIntent, download::DownloadOptions};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let options = DownloadOptions {
+    ///     intent: FileUrlIntent::Download,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let albums: Vec<_> = service.stream_purchased_albums().try_collect().await?;
+    /// for album in albums {
+    ///     if let Some(album_id) = album.id {
+    ///         let path = format!("backup/{}", album_id);
+    ///         service.download_album(&album_id, "27", &path, &options).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_purchased_albums(&self) -> impl Stream<Item = Result<Album, QobuzApiError>> + '_ {
+        paginate(0, 50, move |limit, offset| async move {
+            let purchases = self
+                .get_user_purchases(PurchaseType::Albums, Some(limit), Some(offset))
+                .await?;
+            Ok(purchases.albums.unwrap_or_default())
+        })
+    }
+
+    /// Streams every track individually purchased by the authenticated user.
+    ///
+    /// Further pages are requested from the API only as the stream is consumed.
+    ///
+    /// # Returns
+    /// A stream yielding each purchased `Track`. If a page request fails, the error is
+    /// yielded and the stream ends.
+    pub fn stream_purchased_tracks(&self) -> impl Stream<Item = Result<Track, QobuzApiError>> + '_ {
+        paginate(0, 50, move |limit, offset| async move {
+            let purchases = self
+                .get_user_purchases(PurchaseType::Tracks, Some(limit), Some(offset))
+                .await?;
+            Ok(purchases.tracks.unwrap_or_default())
+        })
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    fs::{read_to_string, rename, write},
+    io::ErrorKind::NotFound,
+    path::{Path, PathBuf},
+    slice::from_ref,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use {
+    serde::{Deserialize, Serialize},
+    serde_json::{from_str, json, to_string, to_string_pretty},
+};
+
+use crate::{
+    api::{requests::AuthPolicy, service::QobuzApiService},
+    errors::QobuzApiError::{self, HttpError, InvalidParameterError, IoError},
+    models::QobuzApiStatusResponse,
+};
+
+/// The kind of a streaming event reported to Qobuz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamingEventKind {
+    /// Playback of a track started
+    Start,
+    /// Playback of a track ended
+    End,
+}
+
+impl StreamingEventKind {
+    /// Returns the API endpoint used to report events of this kind.
+    fn endpoint(&self) -> &'static str {
+        match self {
+            Self::Start => "/track/reportStreamingStart",
+            Self::End => "/track/reportStreamingEnd",
+        }
+    }
+}
+
+/// A streaming event to report to Qobuz.
+///
+/// Qobuz expects playback clients to report when the streaming of a track starts and
+/// ends, so that artists are credited for the plays. Events are created with
+/// [`StreamingEvent::start`] and [`StreamingEvent::end`], which record the current time.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::reporting::{StreamingEvent, StreamingEventKind};
+///
+/// let start = StreamingEvent::start("40128300", "6", "1234567");
+/// let end = StreamingEvent::end("40128300", "6", "1234567", 215);
+///
+/// assert_eq!(start.kind, StreamingEventKind::Start);
+/// assert_eq!(end.duration, Some(215));
+///
+/// // A playback of a downloaded file
+/// let offline = StreamingEvent {
+///     online: false,
+///     local: true,
+///     ..StreamingEvent::start("40128300", "6", "1234567")
+/// };
+/// assert!(offline.local);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamingEvent {
+    /// Whether the event marks the start or the end of the playback
+    pub kind: StreamingEventKind,
+    /// The unique identifier of the played track
+    pub track_id: String,
+    /// The format ID of the stream that was played
+    pub format_id: String,
+    /// The unique identifier of the user playing the track
+    pub user_id: String,
+    /// Unix timestamp of when the event occurred
+    pub date: i64,
+    /// Number of seconds of the track that were played (only set for end events)
+    pub duration: Option<i64>,
+    /// Whether the track was streamed, rather than played from a downloaded copy.
+    /// Defaults to `true`.
+    #[serde(default = "default_online")]
+    pub online: bool,
+    /// Whether the track was played from a file stored on the device. Defaults to `false`.
+    #[serde(default)]
+    pub local: bool,
+}
+
+impl StreamingEvent {
+    /// Creates an event marking the start of a playback, dated now.
+    pub fn start(track_id: &str, format_id: &str, user_id: &str) -> Self {
+        Self::new(
+            StreamingEventKind::Start,
+            track_id,
+            format_id,
+            user_id,
+            None,
+        )
+    }
+
+    /// Creates an event marking the end of a playback, dated now.
+    ///
+    /// `duration` is the number of seconds of the track that were played.
+    pub fn end(track_id: &str, format_id: &str, user_id: &str, duration: i64) -> Self {
+        Self::new(
+            StreamingEventKind::End,
+            track_id,
+            format_id,
+            user_id,
+            Some(duration),
+        )
+    }
+
+    fn new(
+        kind: StreamingEventKind,
+        track_id: &str,
+        format_id: &str,
+        user_id: &str,
+        duration: Option<i64>,
+    ) -> Self {
+        Self {
+            kind,
+            track_id: track_id.to_string(),
+            format_id: format_id.to_string(),
+            user_id: user_id.to_string(),
+            date: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_secs() as i64,
+            duration,
+            online: true,
+            local: false,
+        }
+    }
+}
+
+/// Events queued before the playback source was recorded were all streamed.
+fn default_online() -> bool {
+    true
+}
+
+/// A local queue of streaming events that could not be reported yet.
+///
+/// The queue is persisted as a JSON file, so that events recorded while offline
+/// survive restarts of the player. Queued events are sent with
+/// [`QobuzApiService::flush_streaming_reports`].
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::reporting::{StreamingEvent, StreamingReportQueue};
+///
+/// # fn main() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let path = std::env::temp_dir().join("qobuz_streaming_queue_doctest.json");
+/// # let _ = std::fs::remove_file(&path);
+///
+/// let mut queue = StreamingReportQueue::load(&path)?;
+/// assert!(queue.is_empty());
+///
+/// queue.push(StreamingEvent::start("40128300", "6", "1234567"))?;
+///
+/// // The queued event is persisted to disk
+/// let reloaded = StreamingReportQueue::load(&path)?;
+/// assert_eq!(reloaded.len(), 1);
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamingReportQueue {
+    path: PathBuf,
+    events: Vec<StreamingEvent>,
+}
+
+impl StreamingReportQueue {
+    /// Loads the queue persisted at `path`, or creates an empty queue if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IoError` if the file exists but cannot be read or does not contain a valid queue.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, QobuzApiError> {
+        let path = path.as_ref().to_path_buf();
+
+        let events = match read_to_string(&path) {
+            Ok(content) => from_str(&content).map_err(|e| IoError(e.into()))?,
+            Err(e) if e.kind() == NotFound => Vec::new(),
+            Err(e) => return Err(IoError(e)),
+        };
+
+        Ok(Self { path, events })
+    }
+
+    /// Appends an event to the queue and persists the queue.
+    pub fn push(&mut self, event: StreamingEvent) -> Result<(), QobuzApiError> {
+        self.events.push(event);
+        self.save()
+    }
+
+    /// Returns the queued events, oldest first.
+    pub fn events(&self) -> &[StreamingEvent] {
+        &self.events
+    }
+
+    /// Returns the number of queued events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events are queued.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Writes the queue to its file.
+    ///
+    /// The queue is written to a temporary file in the same folder first, then renamed
+    /// over its file, so that an interrupted save leaves the previous queue intact.
+    pub fn save(&self) -> Result<(), QobuzApiError> {
+        let content = to_string_pretty(&self.events).map_err(|e| IoError(e.into()))?;
+
+        let mut temp_path = self.path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        write(&temp_path, content).map_err(IoError)?;
+        rename(&temp_path, &self.path).map_err(IoError)
+    }
+}
+
+/// Returns `true` if the error indicates that the API could not be reached.
+fn is_offline_error(error: &QobuzApiError) -> bool {
+    matches!(error, HttpError(e) if e.is_connect() || e.is_timeout())
+}
+
+impl QobuzApiService {
+    /// Reports streaming events of the same kind to Qobuz.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - The events to report. All events must be of the same kind.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QobuzApiStatusResponse)` - Response indicating if the events were recorded
+    /// * `Err(QobuzApiError)` - If no events are given, the events are of different kinds,
+    ///   or the API request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::reporting::StreamingEvent;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// let mut service = QobuzApiService::new().await?;
+    /// service.authenticate_with_env().await?;
+    ///
+    /// let event = StreamingEvent::start("40128300", "6", "1234567");
+    /// service.report_streaming_events(&[event]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_streaming_events(
+        &self,
+        events: &[StreamingEvent],
+    ) -> Result<QobuzApiStatusResponse, QobuzApiError> {
+        let Some(first) = events.first() else {
+            return Err(InvalidParameterError {
+                message: "At least one streaming event must be provided".to_string(),
+            });
+        };
+
+        if events.iter().any(|event| event.kind != first.kind) {
+            return Err(InvalidParameterError {
+                message: "All streaming events of a report must be of the same kind".to_string(),
+            });
+        }
+
+        let payload: Vec<_> = events
+            .iter()
+            .map(|event| {
+                let mut entry = json!({
+                    "user_id": event.user_id,
+                    "track_id": event.track_id,
+                    "format_id": event.format_id,
+                    "date": event.date,
+                    "online": event.online,
+                    "local": event.local,
+                });
+
+                if let Some(duration) = event.duration {
+                    entry["duration"] = json!(duration);
+                }
+
+                entry
+            })
+            .collect();
+
+        let params = vec![(
+            "events".to_string(),
+            to_string(&payload).map_err(|e| InvalidParameterError {
+                message: format!("Failed to serialize the streaming events: {}", e),
+            })?,
+        )];
+
+        self.post_with_auth(first.kind.endpoint(), &params, AuthPolicy::Required)
+            .await
+    }
+
+    /// Reports a streaming event, queueing it locally if the API cannot be reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to report
+    /// * `queue` - The queue the event is added to when the API cannot be reached
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the event was reported
+    /// * `Ok(false)` - If the API could not be reached and the event was queued
+    /// * `Err(QobuzApiError)` - If the API rejected the event or the queue could not be saved
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::reporting::{StreamingEvent, StreamingReportQueue};
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let mut queue = StreamingReportQueue::load("streaming_queue.json")?;
+    ///
+    /// let event = StreamingEvent::end("40128300", "6", "1234567", 215);
+    /// if !service.report_streaming_event_or_queue(event, &mut queue).await? {
+    ///     println!("Offline, {} events waiting", queue.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_streaming_event_or_queue(
+        &self,
+        event: StreamingEvent,
+        queue: &mut StreamingReportQueue,
+    ) -> Result<bool, QobuzApiError> {
+        match self.report_streaming_events(from_ref(&event)).await {
+            Ok(_) => Ok(true),
+
+            Err(e) if is_offline_error(&e) => {
+                queue.push(event)?;
+                Ok(false)
+            }
+
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sends all queued streaming events and removes them from the queue.
+    ///
+    /// Start events are reported before end events. If a report fails, the events that
+    /// were not reported stay in the queue, so the flush can be retried later.
+    ///
+    /// # Arguments
+    ///
+    /// * `queue` - The queue to flush
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of events that were reported
+    /// * `Err(QobuzApiError)` - If a report failed or the queue could not be saved
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use qobuz_api_rust::{QobuzApiService, QobuzApiError};
+    /// # use qobuz_api_rust::api::reporting::StreamingReportQueue;
+    /// # async fn example() -> Result<(), QobuzApiError> {
+    /// # let service = QobuzApiService::new().await?;
+    /// let mut queue = StreamingReportQueue::load("streaming_queue.json")?;
+    /// let flushed = service.flush_streaming_reports(&mut queue).await?;
+    /// println!("Reported {} queued events", flushed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn flush_streaming_reports(
+        &self,
+        queue: &mut StreamingReportQueue,
+    ) -> Result<usize, QobuzApiError> {
+        let mut flushed = 0;
+
+        for kind in [StreamingEventKind::Start, StreamingEventKind::End] {
+            let events: Vec<StreamingEvent> = queue
+                .events
+                .iter()
+                .filter(|event| event.kind == kind)
+                .cloned()
+                .collect();
+
+            if events.is_empty() {
+                continue;
+            }
+
+            self.report_streaming_events(&events).await?;
+
+            queue.events.retain(|event| event.kind != kind);
+            queue.save()?;
+            flushed += events.len();
+        }
+
+        Ok(flushed)
+    }
+}
@@ -4,13 +4,72 @@ use {
 };
 
 use crate::{
-    api::service::constants,
-    errors::QobuzApiError::{self, ApiErrorResponse, ApiResponseParseError, HttpError},
+    api::service::{QobuzApiService, constants},
+    errors::QobuzApiError::{
+        self, ApiErrorResponse, ApiResponseParseError, AuthenticationError, HttpError,
+    },
     models::QobuzApiStatusResponse,
     utils::{deserialize_response, get_current_timestamp, get_md5_hash, to_query_string},
 };
 
-impl crate::api::service::QobuzApiService {
+/// How a request authenticates the user.
+///
+/// Endpoints taking a `with_auth` flag convert it into a policy: `Some(true)` sends the
+/// user authentication token if the user is authenticated, while `None` and `Some(false)`
+/// send the request anonymously. Endpoints that only work for an authenticated user use
+/// [`AuthPolicy::Required`].
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::api::requests::AuthPolicy;
+///
+/// assert_eq!(AuthPolicy::from(Some(true)), AuthPolicy::IfAvailable);
+/// assert_eq!(AuthPolicy::from(Some(false)), AuthPolicy::Anonymous);
+/// assert_eq!(AuthPolicy::from(None), AuthPolicy::Anonymous);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthPolicy {
+    /// Send the request without the user authentication token
+    Anonymous,
+    /// Send the user authentication token if the user is authenticated
+    #[default]
+    IfAvailable,
+    /// Send the user authentication token, failing before the request is sent if the
+    /// user is not authenticated
+    Required,
+}
+
+impl From<Option<bool>> for AuthPolicy {
+    fn from(with_auth: Option<bool>) -> Self {
+        if with_auth.unwrap_or(false) {
+            Self::IfAvailable
+        } else {
+            Self::Anonymous
+        }
+    }
+}
+
+impl QobuzApiService {
+    /// Returns the user authentication token to send with a request under the given policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthenticationError` if the policy is [`AuthPolicy::Required`] and the
+    /// user is not authenticated.
+    pub(crate) fn auth_token_for(&self, auth: AuthPolicy) -> Result<Option<&str>, QobuzApiError> {
+        match auth {
+            AuthPolicy::Anonymous => Ok(None),
+            AuthPolicy::IfAvailable => Ok(self.user_auth_token.as_deref()),
+            AuthPolicy::Required => match self.user_auth_token.as_deref() {
+                Some(token) => Ok(Some(token)),
+                None => Err(AuthenticationError {
+                    message: "This request requires an authenticated user".to_string(),
+                }),
+            },
+        }
+    }
+
     /// Sends a GET request to the Qobuz API.
     ///
     /// This method handles the complete request lifecycle including parameter formatting,
@@ -60,6 +119,51 @@ impl crate::api::service::QobuzApiService {
     where
         T: DeserializeOwned,
     {
+        self.get_with_auth(endpoint, params, AuthPolicy::IfAvailable)
+            .await
+    }
+
+    /// Sends a GET request to the Qobuz API with the given authentication policy.
+    ///
+    /// This method behaves like [`get`](Self::get), except that the user authentication
+    /// token is only attached as the policy allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The API endpoint to call (e.g., "/album/get")
+    /// * `params` - A slice of key-value parameter pairs to include in the query string
+    /// * `auth` - Whether the request is sent anonymously, authenticated if possible, or
+    ///   must be authenticated
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use qobuz_api_rust::api::{requests::AuthPolicy, service::QobuzApiService};
+    /// # use qobuz_api_rust::models::UserPlaylists;
+    /// # async fn example(service: &QobuzApiService) -> Result<(), Box<dyn std::error::Error>> {
+    /// let playlists: UserPlaylists = service
+    ///     .get_with_auth("/playlist/getUserPlaylists", &[], AuthPolicy::Required)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`get`](Self::get), this function returns
+    /// `AuthenticationError` without sending the request if the policy is
+    /// [`AuthPolicy::Required`] and the user is not authenticated.
+    pub async fn get_with_auth<T>(
+        &self,
+        endpoint: &str,
+        params: &[(String, String)],
+        auth: AuthPolicy,
+    ) -> Result<T, QobuzApiError>
+    where
+        T: DeserializeOwned,
+    {
+        let token = self.auth_token_for(auth)?;
+
         // Add common parameters
         let all_params = params.to_vec();
 
@@ -68,7 +172,7 @@ impl crate::api::service::QobuzApiService {
 
         let mut request = self.client.get(&url);
 
-        if let Some(ref token) = self.user_auth_token {
+        if let Some(token) = token {
             request = request.header("X-User-Auth-Token", token);
         }
 
@@ -149,12 +253,44 @@ impl crate::api::service::QobuzApiService {
     where
         T: DeserializeOwned,
     {
+        self.post_with_auth(endpoint, params, AuthPolicy::IfAvailable)
+            .await
+    }
+
+    /// Sends a POST request to the Qobuz API with the given authentication policy.
+    ///
+    /// This method behaves like [`post`](Self::post), except that the user authentication
+    /// token is only included as the policy allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The API endpoint to call (e.g., "/playlist/create")
+    /// * `params` - A slice of key-value parameter pairs to include in the form body
+    /// * `auth` - Whether the request is sent anonymously, authenticated if possible, or
+    ///   must be authenticated
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`post`](Self::post), this function returns
+    /// `AuthenticationError` without sending the request if the policy is
+    /// [`AuthPolicy::Required`] and the user is not authenticated.
+    pub async fn post_with_auth<T>(
+        &self,
+        endpoint: &str,
+        params: &[(String, String)],
+        auth: AuthPolicy,
+    ) -> Result<T, QobuzApiError>
+    where
+        T: DeserializeOwned,
+    {
+        let token = self.auth_token_for(auth)?;
+
         // Add common parameters
         let mut all_params = params.to_vec();
         all_params.push(("app_id".to_string(), self.app_id.clone()));
 
-        if let Some(ref token) = self.user_auth_token {
-            all_params.push(("user_auth_token".to_string(), token.clone()));
+        if let Some(token) = token {
+            all_params.push(("user_auth_token".to_string(), token.to_string()));
         }
 
         let url = format!("{}{}", constants::API_BASE_URL, endpoint);
@@ -201,6 +337,7 @@ impl crate::api::service::QobuzApiService {
     /// * `method` - The HTTP method (e.g., "GET", "POST")
     /// * `endpoint` - The API endpoint to call (e.g., "/album/get")
     /// * `params` - A slice of key-value parameter pairs to include in the signature calculation
+    /// * `token` - The user authentication token sent with the request, if any
     ///
     /// # Returns
     ///
@@ -218,6 +355,7 @@ impl crate::api::service::QobuzApiService {
         method: &str,
         endpoint: &str,
         params: &[(String, String)],
+        token: Option<&str>,
     ) -> String {
         let timestamp = get_current_timestamp();
         let mut all_params = params.to_vec();
@@ -225,8 +363,8 @@ impl crate::api::service::QobuzApiService {
         all_params.push(("method".to_string(), method.to_string()));
         all_params.push(("timestamp".to_string(), timestamp.clone()));
 
-        if let Some(ref token) = self.user_auth_token {
-            all_params.push(("user_auth_token".to_string(), token.clone()));
+        if let Some(token) = token {
+            all_params.push(("user_auth_token".to_string(), token.to_string()));
         }
 
         // Sort parameters alphabetically by key
@@ -292,16 +430,48 @@ impl crate::api::service::QobuzApiService {
     where
         T: DeserializeOwned,
     {
+        self.signed_get_with_auth(endpoint, params, AuthPolicy::IfAvailable)
+            .await
+    }
+
+    /// Sends a signed GET request to the Qobuz API with the given authentication policy.
+    ///
+    /// This method behaves like [`signed_get`](Self::signed_get), except that the user
+    /// authentication token is only included, and signed, as the policy allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The API endpoint to call (e.g., "/favorite/create")
+    /// * `params` - A slice of key-value parameter pairs to include in the query string
+    /// * `auth` - Whether the request is sent anonymously, authenticated if possible, or
+    ///   must be authenticated
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`signed_get`](Self::signed_get), this function returns
+    /// `AuthenticationError` without sending the request if the policy is
+    /// [`AuthPolicy::Required`] and the user is not authenticated.
+    pub async fn signed_get_with_auth<T>(
+        &self,
+        endpoint: &str,
+        params: &[(String, String)],
+        auth: AuthPolicy,
+    ) -> Result<T, QobuzApiError>
+    where
+        T: DeserializeOwned,
+    {
+        let token = self.auth_token_for(auth)?;
+
         // Add common parameters
         let mut all_params = params.to_vec();
         all_params.push(("app_id".to_string(), self.app_id.clone()));
 
-        if let Some(ref token) = self.user_auth_token {
-            all_params.push(("user_auth_token".to_string(), token.clone()));
+        if let Some(token) = token {
+            all_params.push(("user_auth_token".to_string(), token.to_string()));
         }
 
         // Generate signature
-        let signature = self.generate_signature("GET", endpoint, params);
+        let signature = self.generate_signature("GET", endpoint, params, token);
         all_params.push(("request_ts".to_string(), get_current_timestamp()));
         all_params.push(("request_sig".to_string(), signature));
 
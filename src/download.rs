@@ -0,0 +1,43 @@
+/// Download configuration for Qobuz tracks and albums.
+///
+/// This module provides the options controlling how tracks and albums are downloaded
+/// by [`QobuzApiService::download_track`](crate::QobuzApiService::download_track) and
+/// [`QobuzApiService::download_album`](crate::QobuzApiService::download_album), such as
+/// which metadata is embedded and whether files are requested for streaming or as
+/// purchased copies. The [`outcome`] module describes the delivered files, [`progress`]
+/// reports the progress of downloads, [`template`] names the downloaded files,
+/// [`verify`] checks them and [`library`] records them to make downloads incremental.
+/// The [`m3u8`] module writes the playlist files of downloaded playlists.
+///
+/// # Usage Examples
+///
+/// ## Downloading a purchased album
+///
+/// ```rust,no_run
+/// use qobuz_api_rust::{
+///     QobuzApiService, api::content::tracks::FileUrlIntent, download::DownloadOptions,
+/// };
+///
+/// # async fn example() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let mut service = QobuzApiService::new().await?;
+/// service.authenticate_with_env().await?;
+///
+/// let options = DownloadOptions {
+///     intent: FileUrlIntent::Download,
+///     ..Default::default()
+/// };
+/// service
+///     .download_album("12345", "27", "downloads/Artist/Album", &options)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub mod library;
+pub mod m3u8;
+pub mod options;
+pub mod outcome;
+pub mod progress;
+pub mod template;
+pub mod verify;
+
+pub use options::DownloadOptions;
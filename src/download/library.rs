@@ -0,0 +1,393 @@
+use std::{
+    collections::BTreeMap,
+    fs::{File, create_dir_all, metadata, read_to_string, rename, write},
+    io::{Error as StdIoError, ErrorKind::NotFound, Read, Result as IoResult},
+    path::{Path, PathBuf, absolute},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use {
+    md5::Context,
+    serde::{Deserialize, Serialize},
+    serde_json::{from_str, to_string_pretty},
+    tokio::task::spawn_blocking,
+};
+
+use crate::{
+    download::{
+        outcome::{DeliveredFormat, DownloadOutcome},
+        verify::Verification,
+    },
+    errors::QobuzApiError::{self, InvalidParameterError, IoError},
+    utils::get_current_timestamp,
+};
+
+/// Version of the download manifest format written by this library.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// The name of the manifest file in the root folder of a library.
+pub const MANIFEST_FILE_NAME: &str = ".qobuz-manifest.json";
+
+/// Ranks a format ID by audio quality, from MP3 320 to FLAC 24-bit/192kHz.
+///
+/// Unknown formats rank lowest.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::library::format_rank;
+///
+/// assert!(format_rank("27") > format_rank("6"));
+/// assert!(format_rank("6") > format_rank("5"));
+/// ```
+pub fn format_rank(format_id: &str) -> u8 {
+    match format_id {
+        "5" => 1,
+        "6" => 2,
+        "7" => 3,
+        "27" => 4,
+        _ => 0,
+    }
+}
+
+/// A track file recorded in a [`DownloadManifest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// The ID of the track
+    pub track_id: String,
+    /// The ID of the album the track was downloaded with, if known
+    pub album_id: Option<String>,
+    /// The format ID that was requested
+    pub requested_format_id: String,
+    /// The format of the delivered file
+    pub delivered: DeliveredFormat,
+    /// The path of the file, relative to the library root with `/` separators, or absolute
+    /// if the file was saved outside the library
+    pub path: String,
+    /// The size of the tagged file in bytes
+    pub size: u64,
+    /// The MD5 checksum of the tagged file, in lowercase hexadecimal
+    pub md5: String,
+    /// Unix timestamp of when the file was downloaded
+    pub downloaded_at: i64,
+}
+
+/// The state of the file of a [`ManifestEntry`] on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryCheck {
+    /// The file is present and matches the manifest
+    Valid,
+    /// The file is missing
+    Missing,
+    /// The file differs from the manifest, with the reason
+    Corrupt(String),
+}
+
+impl ManifestEntry {
+    /// Checks the file of the entry, found relative to the library `root`.
+    ///
+    /// How much is checked follows `level`: [`Verification::Skip`] only checks that the
+    /// file exists, [`Verification::Structure`] also compares its size, and
+    /// [`Verification::Decode`] also compares its MD5 checksum.
+    pub fn check<P: AsRef<Path>>(&self, root: P, level: Verification) -> EntryCheck {
+        let path = root.as_ref().join(&self.path);
+        let size = match metadata(&path) {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == NotFound => return EntryCheck::Missing,
+            Err(e) => return EntryCheck::Corrupt(e.to_string()),
+        };
+
+        if level == Verification::Skip {
+            return EntryCheck::Valid;
+        }
+
+        if size != self.size {
+            return EntryCheck::Corrupt(format!("file has {} bytes, expected {}", size, self.size));
+        }
+
+        if level == Verification::Decode {
+            match file_md5(&path) {
+                Ok(md5) if md5 != self.md5 => {
+                    return EntryCheck::Corrupt("file does not match its checksum".to_string());
+                }
+                Ok(_) => {}
+                Err(e) => return EntryCheck::Corrupt(e.to_string()),
+            }
+        }
+
+        EntryCheck::Valid
+    }
+
+    /// Returns `true` if the file is in a lower format than `format_id`, so that a better
+    /// format may have become available.
+    pub fn is_below(&self, format_id: &str) -> bool {
+        self.rank() < format_rank(format_id)
+    }
+
+    /// Returns `true` if a delivered file would be in a better format than the recorded one.
+    pub fn is_improved_by(&self, delivered: &DeliveredFormat) -> bool {
+        !delivered.sample
+            && delivered
+                .format_id
+                .is_some_and(|id| format_rank(&id.to_string()) > self.rank())
+    }
+
+    /// Ranks the recorded file with [`format_rank`], samples lowest.
+    ///
+    /// A file without a delivered format ID is ranked by its requested format ID.
+    fn rank(&self) -> u8 {
+        if self.delivered.sample {
+            return 0;
+        }
+
+        let format_id = self
+            .delivered
+            .format_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| self.requested_format_id.clone());
+        format_rank(&format_id)
+    }
+}
+
+/// The record of the tracks downloaded into a library.
+///
+/// The manifest is stored as versioned JSON in [`MANIFEST_FILE_NAME`], in the root folder
+/// of the library, with one entry per track ID.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::library::DownloadManifest;
+///
+/// # fn main() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let path = std::env::temp_dir().join("qobuz_manifest_doctest.json");
+/// DownloadManifest::default().save(&path)?;
+///
+/// let manifest = DownloadManifest::load(&path)?;
+/// assert!(manifest.entries.is_empty());
+/// # std::fs::remove_file(&path)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DownloadManifest {
+    /// Version of the manifest format
+    pub version: u32,
+    /// The downloaded tracks, by track ID
+    pub entries: BTreeMap<String, ManifestEntry>,
+}
+
+impl Default for DownloadManifest {
+    fn default() -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl DownloadManifest {
+    /// Loads a manifest from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IoError` if the file cannot be read or parsed, and `InvalidParameterError`
+    /// if the manifest was written by a newer, unsupported version of the format.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, QobuzApiError> {
+        let content = read_to_string(path).map_err(IoError)?;
+        let manifest: Self = from_str(&content).map_err(|e| IoError(e.into()))?;
+
+        if manifest.version > MANIFEST_VERSION {
+            return Err(InvalidParameterError {
+                message: format!(
+                    "Unsupported download manifest version {} (latest supported is {})",
+                    manifest.version, MANIFEST_VERSION
+                ),
+            });
+        }
+
+        Ok(manifest)
+    }
+
+    /// Writes the manifest to a JSON file.
+    ///
+    /// The manifest is written to a temporary file in the same folder first, then renamed
+    /// over `path`, so that an interrupted save leaves the previous manifest intact.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), QobuzApiError> {
+        let path = path.as_ref();
+        let content = to_string_pretty(self).map_err(|e| IoError(e.into()))?;
+
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        write(&temp_path, content).map_err(IoError)?;
+        rename(&temp_path, path).map_err(IoError)
+    }
+}
+
+/// A library folder and the manifest of the tracks downloaded into it.
+///
+/// Set a library in [`DownloadOptions::library`](crate::download::DownloadOptions::library)
+/// to make downloads incremental: a track recorded in the manifest is reused if its file
+/// is still valid, downloaded again if the file is missing or corrupt, and upgraded if a
+/// better format than the recorded one has become available. Every downloaded track is
+/// recorded, and the manifest is saved after each one, so an interrupted download loses
+/// nothing.
+///
+/// The library may be shared by concurrent downloads.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+///
+/// use qobuz_api_rust::{
+///     QobuzApiService,
+///     download::{DownloadOptions, library::Library},
+/// };
+///
+/// # async fn example() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let service = QobuzApiService::new().await?;
+/// let options = DownloadOptions {
+///     library: Some(Arc::new(Library::open("Music")?)),
+///     ..Default::default()
+/// };
+///
+/// // Only the tracks missing from the library are downloaded
+/// service
+///     .download_album("12345", "6", "Music/Artist/Album", &options)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Library {
+    root: PathBuf,
+    manifest: Mutex<DownloadManifest>,
+}
+
+impl Library {
+    /// Opens the library in the `root` folder, loading its manifest if there is one.
+    ///
+    /// A relative `root` is resolved against the current directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root cannot be resolved or an existing manifest cannot be
+    /// loaded.
+    pub fn open<P: AsRef<Path>>(root: P) -> Result<Self, QobuzApiError> {
+        let root = absolute(root).map_err(IoError)?;
+        let manifest_path = root.join(MANIFEST_FILE_NAME);
+
+        let manifest = if manifest_path.exists() {
+            DownloadManifest::load(&manifest_path)?
+        } else {
+            DownloadManifest::default()
+        };
+
+        Ok(Self {
+            root,
+            manifest: Mutex::new(manifest),
+        })
+    }
+
+    /// Returns the root folder of the library, as an absolute path.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the path of the manifest file.
+    pub fn manifest_path(&self) -> PathBuf {
+        self.root.join(MANIFEST_FILE_NAME)
+    }
+
+    /// Returns a copy of the manifest.
+    pub fn manifest(&self) -> DownloadManifest {
+        self.lock().clone()
+    }
+
+    /// Returns the manifest entry of a track, if it was downloaded into the library.
+    pub fn entry(&self, track_id: &str) -> Option<ManifestEntry> {
+        self.lock().entries.get(track_id).cloned()
+    }
+
+    /// Returns the path of the file of an entry.
+    pub fn path_of(&self, entry: &ManifestEntry) -> PathBuf {
+        self.root.join(&entry.path)
+    }
+
+    /// Records a downloaded track and saves the manifest.
+    ///
+    /// The size and checksum are read from the file at `outcome.path`, so it should be
+    /// called once the metadata is embedded.
+    pub async fn record(
+        &self,
+        outcome: &DownloadOutcome,
+        album_id: Option<&str>,
+    ) -> Result<ManifestEntry, QobuzApiError> {
+        let path = absolute(&outcome.path).map_err(IoError)?;
+
+        // Hashing reads the whole file, keep it off the async runtime
+        let hashed_path = path.clone();
+        let (size, md5) = spawn_blocking(move || {
+            let size = metadata(&hashed_path)?.len();
+            Ok::<_, StdIoError>((size, file_md5(&hashed_path)?))
+        })
+        .await
+        .map_err(|e| IoError(StdIoError::other(e)))?
+        .map_err(IoError)?;
+
+        let entry = ManifestEntry {
+            track_id: outcome.track_id.clone(),
+            album_id: album_id.map(str::to_string),
+            requested_format_id: outcome.requested_format_id.clone(),
+            delivered: outcome.delivered.clone(),
+            path: self.relative_path(&path),
+            size,
+            md5,
+            downloaded_at: get_current_timestamp().parse().unwrap_or_default(),
+        };
+
+        create_dir_all(&self.root).map_err(IoError)?;
+        let mut manifest = self.lock();
+        manifest
+            .entries
+            .insert(entry.track_id.clone(), entry.clone());
+        manifest.save(self.manifest_path())?;
+
+        Ok(entry)
+    }
+
+    /// Returns the absolute `path` relative to the library root with `/` separators, or
+    /// unchanged if it is outside the library.
+    fn relative_path(&self, path: &Path) -> String {
+        match path.strip_prefix(&self.root) {
+            Ok(relative) => relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            Err(_) => path.to_string_lossy().into_owned(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, DownloadManifest> {
+        self.manifest.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Computes the MD5 checksum of a file, in lowercase hexadecimal.
+fn file_md5(path: &Path) -> IoResult<String> {
+    let mut file = File::open(path)?;
+    let mut context = Context::new();
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        context.consume(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", context.finalize()))
+}
@@ -0,0 +1,139 @@
+use std::{
+    fs::{create_dir_all, write},
+    path::{Component, Path, absolute},
+};
+
+use crate::errors::QobuzApiError::{self, IoError};
+
+/// A track listed in an extended M3U8 playlist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct M3u8Entry {
+    /// The path of the file, relative to the folder of the playlist file
+    pub path: String,
+    /// The duration of the track in seconds, if known
+    pub duration: Option<i64>,
+    /// The title displayed by players, such as "Artist - Title"
+    pub title: String,
+}
+
+/// Renders an extended M3U8 playlist.
+///
+/// Each entry is written as an `#EXTINF` line with its duration and title, followed by its
+/// path. Unknown durations are written as `-1`, and line breaks in titles are replaced by
+/// spaces.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::m3u8::{M3u8Entry, render_m3u8};
+///
+/// let entries = [M3u8Entry {
+///     path: "Daft Punk/Discovery/01. One More Time.flac".to_string(),
+///     duration: Some(320),
+///     title: "Daft Punk - One More Time".to_string(),
+/// }];
+///
+/// assert_eq!(
+///     render_m3u8(Some("Favorites"), &entries),
+///     "#EXTM3U\n#PLAYLIST:Favorites\n\
+///      #EXTINF:320,Daft Punk - One More Time\n\
+///      Daft Punk/Discovery/01. One More Time.flac\n"
+/// );
+/// ```
+pub fn render_m3u8(name: Option<&str>, entries: &[M3u8Entry]) -> String {
+    let mut content = String::from("#EXTM3U\n");
+    if let Some(name) = name {
+        content.push_str(&format!("#PLAYLIST:{}\n", single_line(name)));
+    }
+
+    for entry in entries {
+        content.push_str(&format!(
+            "#EXTINF:{},{}\n{}\n",
+            entry.duration.unwrap_or(-1),
+            single_line(&entry.title),
+            entry.path
+        ));
+    }
+
+    content
+}
+
+/// Writes an extended M3U8 playlist to a file, creating its folder if needed.
+///
+/// See [`render_m3u8`] for the content of the file.
+pub fn write_m3u8<P: AsRef<Path>>(
+    path: P,
+    name: Option<&str>,
+    entries: &[M3u8Entry],
+) -> Result<(), QobuzApiError> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(IoError)?;
+    }
+
+    write(path, render_m3u8(name, entries)).map_err(IoError)
+}
+
+/// Returns the path of `target` relative to the folder `base`, with `/` separators.
+///
+/// Both paths are made absolute against the current directory first, so they may be
+/// given relative to it. If they share no root, as on different Windows drives, the
+/// absolute path of `target` is returned.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::m3u8::relative_path;
+///
+/// assert_eq!(
+///     relative_path("Music/Playlists", "Music/Artist/Album/01. Title.flac").unwrap(),
+///     "../Artist/Album/01. Title.flac"
+/// );
+/// ```
+pub fn relative_path<B: AsRef<Path>, T: AsRef<Path>>(
+    base: B,
+    target: T,
+) -> Result<String, QobuzApiError> {
+    let target = absolute(target).map_err(IoError)?;
+    let base = normalized_components(&absolute(base).map_err(IoError)?);
+    let target_components = normalized_components(&target);
+
+    let common = base
+        .iter()
+        .zip(&target_components)
+        .take_while(|(base, target)| base == target)
+        .count();
+
+    // Paths on different drives have no relative path
+    if common == 0 {
+        return Ok(target.to_string_lossy().into_owned());
+    }
+
+    let segments: Vec<String> = base[common..]
+        .iter()
+        .map(|_| "..".to_string())
+        .chain(target_components[common..].iter().cloned())
+        .collect();
+
+    Ok(segments.join("/"))
+}
+
+/// Returns the components of an absolute path, with `.` and `..` resolved.
+fn normalized_components(path: &Path) -> Vec<String> {
+    let mut components: Vec<String> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                components.pop();
+            }
+            component => components.push(component.as_os_str().to_string_lossy().into_owned()),
+        }
+    }
+    components
+}
+
+/// Replaces the line breaks of a value, which would end its line in the playlist.
+fn single_line(value: &str) -> String {
+    value.replace(['\r', '\n'], " ")
+}
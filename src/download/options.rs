@@ -0,0 +1,183 @@
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+use crate::{
+    api::content::tracks::FileUrlIntent,
+    download::{
+        library::Library,
+        outcome::QualityPolicy,
+        progress::{ConsoleProgress, ProgressObserver},
+        template::{DiscLayout, PathTemplate},
+        verify::Verification,
+    },
+    metadata::MetadataConfig,
+};
+
+/// Options controlling the download of tracks and albums.
+///
+/// The default options embed the default metadata and request the files with the
+/// streaming intent, matching the behavior of the Qobuz players.
+///
+/// # Examples
+///
+/// Download purchased files exactly as bought, without cover art:
+///
+/// ```rust
+/// use qobuz_api_rust::{
+///     api::content::tracks::FileUrlIntent, download::DownloadOptions, metadata::MetadataConfig,
+/// };
+///
+/// let options = DownloadOptions {
+///     metadata: MetadataConfig {
+///         cover_art: false,
+///         ..Default::default()
+///     },
+///     intent: FileUrlIntent::Download,
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Decode downloaded FLAC files to check them against their MD5 signature:
+///
+/// ```rust
+/// use qobuz_api_rust::download::{DownloadOptions, verify::Verification};
+///
+/// let options = DownloadOptions {
+///     verification: Verification::Decode,
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Report the progress to a closure instead of the console:
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use qobuz_api_rust::download::{DownloadOptions, progress::ProgressEvent};
+///
+/// let options = DownloadOptions {
+///     progress: Arc::new(|event: &ProgressEvent| {
+///         eprintln!("{}: {:?} {} bytes", event.track_id, event.phase, event.downloaded);
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Name the tracks after their artist, numbering multi-disc albums as `1-01`:
+///
+/// ```rust
+/// use qobuz_api_rust::download::DownloadOptions;
+///
+/// let options = DownloadOptions {
+///     track_template: "[{disc}-]{track:02}. {album_artist} - {title}".parse().unwrap(),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Skip the tracks already downloaded into a library:
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+///
+/// use qobuz_api_rust::download::{DownloadOptions, library::Library};
+///
+/// # fn main() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let options = DownloadOptions {
+///     library: Some(Arc::new(Library::open("Music")?)),
+///     ..Default::default()
+/// };
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Download four tracks of an album at the same time:
+///
+/// ```rust
+/// use qobuz_api_rust::download::DownloadOptions;
+///
+/// let options = DownloadOptions {
+///     concurrency: 4,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone)]
+pub struct DownloadOptions {
+    /// Which metadata tags to embed in the downloaded files.
+    pub metadata: MetadataConfig,
+    /// The intent used when requesting the file URLs.
+    ///
+    /// Use [`FileUrlIntent::Download`] to download purchased albums and tracks.
+    pub intent: FileUrlIntent,
+    /// The number of tracks of an album downloaded at the same time.
+    ///
+    /// The downloaded files are the same whatever the concurrency. Defaults to 1, which
+    /// downloads the tracks one after another.
+    pub concurrency: usize,
+    /// How thoroughly each downloaded file is checked before its metadata is embedded.
+    ///
+    /// A file failing verification is removed and its download fails with a
+    /// `DownloadError`. Defaults to [`Verification::Structure`].
+    pub verification: Verification,
+    /// The observer receiving the progress of the downloads.
+    ///
+    /// Defaults to [`ConsoleProgress`], which prints the progress to the console. Use
+    /// [`NoProgress`](crate::download::progress::NoProgress) to download silently.
+    pub progress: Arc<dyn ProgressObserver>,
+    /// The template of the track file paths of an album, relative to the album folder and
+    /// without the file extension.
+    ///
+    /// Defaults to [`PathTemplate::DEFAULT_TRACK`], `[CD{disc}/]{track:02}. {title}`, which
+    /// puts the tracks of multi-disc albums in one folder per disc. See [`DiscLayout`] for
+    /// other layouts.
+    pub track_template: PathTemplate,
+    /// The template of album folders, relative to the library or download folder.
+    ///
+    /// Defaults to [`PathTemplate::DEFAULT_ALBUM`], `{album_artist}/{album}`. It is used
+    /// where the album folder is not given, as by
+    /// [`QobuzApiService::sync_library`](crate::QobuzApiService::sync_library).
+    pub album_template: PathTemplate,
+    /// What to do when the delivered file differs from the requested format.
+    ///
+    /// Defaults to [`QualityPolicy::Fallback`], which saves the full track in the best
+    /// format delivered but refuses samples.
+    pub quality_policy: QualityPolicy,
+    /// The library recording the downloaded tracks, if downloads should be incremental.
+    ///
+    /// Defaults to `None`, which downloads every track. See [`Library`] for how the
+    /// recorded tracks are reused, repaired and upgraded.
+    pub library: Option<Arc<Library>>,
+}
+
+impl Debug for DownloadOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("DownloadOptions")
+            .field("metadata", &self.metadata)
+            .field("intent", &self.intent)
+            .field("concurrency", &self.concurrency)
+            .field("verification", &self.verification)
+            .field("track_template", &self.track_template)
+            .field("album_template", &self.album_template)
+            .field("quality_policy", &self.quality_policy)
+            .field("library", &self.library)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self {
+            metadata: MetadataConfig::default(),
+            intent: FileUrlIntent::default(),
+            concurrency: 1,
+            verification: Verification::default(),
+            progress: Arc::new(ConsoleProgress::default()),
+            track_template: DiscLayout::default().track_template(),
+            album_template: PathTemplate::parse(PathTemplate::DEFAULT_ALBUM)
+                .expect("the default album template is valid"),
+            quality_policy: QualityPolicy::default(),
+            library: None,
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::QobuzApiError::{self, DownloadError},
+    models::FileUrl,
+};
+
+/// The extensions of the audio files delivered by Qobuz.
+pub const AUDIO_EXTENSIONS: [&str; 3] = ["flac", "mp3", "m4a"];
+
+/// Returns the file extension of an audio MIME type, or `None` for other types.
+///
+/// Parameters such as `; charset=binary` are ignored.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::outcome::extension_for_mime_type;
+///
+/// assert_eq!(extension_for_mime_type("audio/flac"), Some("flac"));
+/// assert_eq!(extension_for_mime_type("audio/mpeg"), Some("mp3"));
+/// assert_eq!(extension_for_mime_type("application/octet-stream"), None);
+/// ```
+pub fn extension_for_mime_type(mime_type: &str) -> Option<&'static str> {
+    let essence = mime_type.split(';').next().unwrap_or_default().trim();
+
+    match essence.to_ascii_lowercase().as_str() {
+        "audio/flac" | "audio/x-flac" => Some("flac"),
+        "audio/mpeg" | "audio/mp3" | "audio/mpeg3" => Some("mp3"),
+        "audio/mp4" | "audio/x-m4a" | "audio/aac" => Some("m4a"),
+        _ => None,
+    }
+}
+
+/// Returns the file extension expected for a format ID, before the delivered format is
+/// known.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::outcome::extension_for_format_id;
+///
+/// assert_eq!(extension_for_format_id("5"), "mp3");
+/// assert_eq!(extension_for_format_id("27"), "flac");
+/// ```
+pub fn extension_for_format_id(format_id: &str) -> &'static str {
+    match format_id {
+        "5" => "mp3",
+        _ => "flac",
+    }
+}
+
+/// What to do when the file delivered for a track differs from the requested one.
+///
+/// For some tracks or accounts, Qobuz delivers a lower format than requested, for example
+/// when the album is not available in hi-res, or a 30-second sample instead of the full
+/// track.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QualityPolicy {
+    /// Save whatever is delivered, including samples
+    Accept,
+    /// Save the full track in the best format delivered, even if lower than requested, but
+    /// refuse samples
+    #[default]
+    Fallback,
+    /// Refuse samples and any format other than the requested one
+    Fail,
+}
+
+/// The format of a delivered file, as described by getFileUrl.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeliveredFormat {
+    /// The format ID of the file, such as 6 for FLAC 16-bit/44.1kHz
+    pub format_id: Option<i32>,
+    /// The bit depth of the file
+    pub bit_depth: Option<i32>,
+    /// The sampling rate of the file in kHz
+    pub sampling_rate: Option<f64>,
+    /// The MIME type of the file
+    pub mime_type: Option<String>,
+    /// Whether the file is a 30-second sample instead of the full track
+    pub sample: bool,
+    /// The codes of the restrictions explaining why the file differs from the requested one
+    pub restrictions: Vec<String>,
+}
+
+impl From<&FileUrl> for DeliveredFormat {
+    fn from(file_url: &FileUrl) -> Self {
+        Self {
+            format_id: file_url.format_id,
+            bit_depth: file_url.bit_depth,
+            sampling_rate: file_url.sampling_rate,
+            mime_type: file_url.mime_type.clone(),
+            sample: file_url.is_sample(),
+            restrictions: file_url.restriction_codes(),
+        }
+    }
+}
+
+/// How the delivered file compares with the requested format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualityMatch {
+    /// The full track was delivered in the requested format
+    Requested,
+    /// The full track was delivered in another format than requested
+    Downgraded,
+    /// A sample was delivered instead of the full track
+    Sample,
+}
+
+impl QualityMatch {
+    /// Compares a delivered file with the requested format ID.
+    ///
+    /// The bit depth and sampling rate of a format are a maximum, so a 24-bit/48kHz file
+    /// delivered as format 27 is the requested format. A file below the minimum of the
+    /// requested format, such as a 16-bit/44.1kHz file delivered for a hi-res request, is
+    /// downgraded whatever its format ID. A file without a format ID, bit depth or sampling
+    /// rate is assumed to match the requested format on that property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qobuz_api_rust::download::outcome::{DeliveredFormat, QualityMatch};
+    ///
+    /// let delivered = DeliveredFormat {
+    ///     format_id: Some(6),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(QualityMatch::of(&delivered, "27"), QualityMatch::Downgraded);
+    /// assert_eq!(QualityMatch::of(&delivered, "6"), QualityMatch::Requested);
+    ///
+    /// let delivered = DeliveredFormat {
+    ///     format_id: Some(27),
+    ///     bit_depth: Some(16),
+    ///     sampling_rate: Some(44.1),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(QualityMatch::of(&delivered, "27"), QualityMatch::Downgraded);
+    /// ```
+    pub fn of(delivered: &DeliveredFormat, requested_format_id: &str) -> Self {
+        let below_minimum = minimum_quality(requested_format_id).is_some_and(
+            |(min_bit_depth, min_sampling_rate)| {
+                delivered
+                    .bit_depth
+                    .is_some_and(|depth| depth < min_bit_depth)
+                    || delivered
+                        .sampling_rate
+                        .is_some_and(|rate| rate < min_sampling_rate)
+            },
+        );
+
+        if delivered.sample {
+            Self::Sample
+        } else if below_minimum
+            || delivered
+                .format_id
+                .is_some_and(|id| id.to_string() != requested_format_id)
+        {
+            Self::Downgraded
+        } else {
+            Self::Requested
+        }
+    }
+}
+
+/// Returns the minimum bit depth and sampling rate in kHz of a lossless format.
+///
+/// Hi-res formats have 24-bit files, at any sampling rate up to their maximum.
+fn minimum_quality(format_id: &str) -> Option<(i32, f64)> {
+    match format_id {
+        "6" => Some((16, 44.1)),
+        "7" | "27" => Some((24, 44.1)),
+        _ => None,
+    }
+}
+
+impl QualityPolicy {
+    /// Checks a delivered file against the policy.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QualityMatch)` - How the delivered file compares with the requested format, if
+    ///   the policy accepts it
+    /// * `Err(QobuzApiError)` - A `DownloadError` if the policy refuses the file
+    pub fn check(
+        &self,
+        delivered: &DeliveredFormat,
+        requested_format_id: &str,
+    ) -> Result<QualityMatch, QobuzApiError> {
+        let quality = QualityMatch::of(delivered, requested_format_id);
+
+        let refused = match (self, quality) {
+            (Self::Accept, _) | (_, QualityMatch::Requested) => false,
+            (Self::Fallback, QualityMatch::Downgraded) => false,
+            (Self::Fallback | Self::Fail, QualityMatch::Sample) => true,
+            (Self::Fail, QualityMatch::Downgraded) => true,
+        };
+
+        if refused {
+            let restrictions = if delivered.restrictions.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", delivered.restrictions.join(", "))
+            };
+
+            return Err(DownloadError {
+                message: match quality {
+                    QualityMatch::Sample => {
+                        format!("Only a sample of the track is available{}", restrictions)
+                    }
+                    _ => format!(
+                        "Format {} was requested but format {} ({}-bit/{}kHz) was delivered{}",
+                        requested_format_id,
+                        delivered.format_id.unwrap_or_default(),
+                        delivered
+                            .bit_depth
+                            .map_or("?".to_string(), |depth| depth.to_string()),
+                        delivered
+                            .sampling_rate
+                            .map_or("?".to_string(), |rate| rate.to_string()),
+                        restrictions
+                    ),
+                },
+            });
+        }
+
+        Ok(quality)
+    }
+}
+
+/// What a track download did to obtain the file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DownloadAction {
+    /// The track was not in the library and was downloaded
+    #[default]
+    Downloaded,
+    /// The file recorded in the library was valid and reused
+    Reused,
+    /// The file recorded in the library was missing or corrupt and was downloaded again
+    Repaired,
+    /// A better format than the one recorded in the library was downloaded
+    Upgraded,
+}
+
+/// The result of a successful track download.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadOutcome {
+    /// The ID of the downloaded track
+    pub track_id: String,
+    /// The path the track was saved to
+    ///
+    /// The extension matches the delivered format, so it may differ from the requested
+    /// path, for example when an MP3 is delivered instead of a FLAC.
+    pub path: String,
+    /// The format ID that was requested
+    pub requested_format_id: String,
+    /// The format of the delivered file
+    pub delivered: DeliveredFormat,
+    /// How the delivered file compares with the requested format
+    pub quality: QualityMatch,
+    /// Whether the file was downloaded or reused from the library
+    pub action: DownloadAction,
+}
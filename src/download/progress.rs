@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::{Write, stdout},
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicUsize, Ordering::SeqCst},
+    },
+};
+
+use tokio::sync::mpsc::UnboundedSender;
+
+/// The step a track download is at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadPhase {
+    /// The file URL of the track is being requested
+    ResolvingUrl,
+    /// The audio file is being downloaded
+    Downloading,
+    /// The downloaded file is being verified
+    Verifying,
+    /// Metadata is being embedded in the downloaded file
+    Tagging,
+    /// The track was downloaded, verified and tagged
+    Completed,
+    /// The request signature was rejected, and the app credentials are being refreshed
+    RefreshingCredentials,
+    /// The download failed with the given error and is attempted again
+    Retrying(String),
+}
+
+/// The position of a track in an album download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlbumPosition {
+    /// The position of the track in the album, starting at 1
+    pub track_position: usize,
+    /// The number of tracks downloaded with the album
+    pub track_count: usize,
+    /// The number of tracks of the album completed so far
+    pub completed_tracks: usize,
+}
+
+/// A progress update of a track download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressEvent {
+    /// The ID of the track being downloaded
+    pub track_id: String,
+    /// The path the track is saved to
+    pub path: String,
+    /// The step the download is at
+    pub phase: DownloadPhase,
+    /// The number of bytes of the file downloaded so far
+    pub downloaded: u64,
+    /// The size of the file in bytes, if known
+    pub total: Option<u64>,
+    /// The position of the track, if it is downloaded as part of an album
+    pub album: Option<AlbumPosition>,
+}
+
+/// Receives the progress of track and album downloads.
+///
+/// An observer is set in [`DownloadOptions::progress`](crate::download::DownloadOptions::progress).
+/// Concurrent track downloads report to the same observer, so events of different tracks
+/// may be interleaved. Besides the console output of [`ConsoleProgress`], observers are
+/// implemented for closures and for Tokio channel senders.
+///
+/// # Example
+///
+/// Forward the events to a channel, for example to update a user interface:
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use qobuz_api_rust::download::{DownloadOptions, progress::ProgressEvent};
+/// use tokio::sync::mpsc::unbounded_channel;
+///
+/// let (sender, mut receiver) = unbounded_channel::<ProgressEvent>();
+/// let options = DownloadOptions {
+///     progress: Arc::new(sender),
+///     ..Default::default()
+/// };
+/// ```
+pub trait ProgressObserver: Send + Sync {
+    /// Called for every progress update of a download.
+    fn on_progress(&self, event: &ProgressEvent);
+}
+
+impl<F> ProgressObserver for F
+where
+    F: Fn(&ProgressEvent) + Send + Sync,
+{
+    fn on_progress(&self, event: &ProgressEvent) {
+        self(event)
+    }
+}
+
+impl ProgressObserver for UnboundedSender<ProgressEvent> {
+    fn on_progress(&self, event: &ProgressEvent) {
+        // The receiver may stop listening before the download ends
+        let _ = self.send(event.clone());
+    }
+}
+
+/// An observer ignoring every progress update.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl ProgressObserver for NoProgress {
+    fn on_progress(&self, _event: &ProgressEvent) {}
+}
+
+/// An observer printing the progress to the console.
+///
+/// A single track shows its bytes downloaded, while an album shows one line aggregated
+/// across its concurrent track downloads. This is the default observer.
+#[derive(Default)]
+pub struct ConsoleProgress {
+    state: Mutex<ConsoleState>,
+}
+
+#[derive(Default)]
+struct ConsoleState {
+    /// Bytes downloaded so far, per track of the album being downloaded
+    album_downloaded: HashMap<String, u64>,
+    /// Whether the progress line is displayed and must be ended before other output
+    line_open: bool,
+}
+
+impl Debug for ConsoleProgress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ConsoleProgress").finish_non_exhaustive()
+    }
+}
+
+impl ConsoleState {
+    /// Prints a message on its own line, below the progress line.
+    fn message(&mut self, message: &str) {
+        if self.line_open {
+            println!();
+            self.line_open = false;
+        }
+        println!("{}", message);
+    }
+
+    /// Replaces the progress line.
+    fn progress_line(&mut self, line: &str) {
+        print!("\r{}", line);
+        // Progress display is best effort
+        let _ = stdout().flush();
+        self.line_open = true;
+    }
+
+    fn track_event(&mut self, event: &ProgressEvent) {
+        match &event.phase {
+            DownloadPhase::Downloading => match event.total {
+                Some(total) => self.progress_line(&format!(
+                    "Progress: {}/{} bytes ({:.2}%)",
+                    event.downloaded,
+                    total,
+                    (event.downloaded as f64 / total as f64) * 100.0
+                )),
+                None => self.progress_line(&format!("Downloaded: {} bytes", event.downloaded)),
+            },
+            DownloadPhase::Verifying => self.message(&format!("Verifying {}", event.path)),
+            DownloadPhase::Tagging => {
+                self.message(&format!("Embedding metadata in {}", event.path))
+            }
+            DownloadPhase::RefreshingCredentials => {
+                self.message("Invalid signature detected, refreshing app credentials...")
+            }
+            DownloadPhase::Retrying(error) => self.message(&format!("Retrying: {}", error)),
+            DownloadPhase::ResolvingUrl | DownloadPhase::Completed => {}
+        }
+    }
+
+    fn album_event(&mut self, event: &ProgressEvent, album: &AlbumPosition) {
+        match &event.phase {
+            DownloadPhase::ResolvingUrl => self.message(&format!(
+                "Downloading track {}/{}: {}",
+                album.track_position, album.track_count, event.path
+            )),
+            DownloadPhase::Downloading => {
+                self.album_downloaded
+                    .insert(event.track_id.clone(), event.downloaded);
+            }
+            DownloadPhase::RefreshingCredentials => self.message(&format!(
+                "Invalid signature detected for track {}/{}, refreshing app credentials...",
+                album.track_position, album.track_count
+            )),
+            DownloadPhase::Retrying(error) => self.message(&format!(
+                "Retrying track {}/{}: {} ({})",
+                album.track_position, album.track_count, event.path, error
+            )),
+            DownloadPhase::Verifying | DownloadPhase::Tagging | DownloadPhase::Completed => {}
+        }
+
+        let downloaded: u64 = self.album_downloaded.values().sum();
+        self.progress_line(&format!(
+            "Album progress: {}/{} tracks, {:.1} MB downloaded",
+            album.completed_tracks,
+            album.track_count,
+            downloaded as f64 / 1_000_000.0
+        ));
+
+        if event.phase == DownloadPhase::Completed && album.completed_tracks == album.track_count {
+            self.message(&format!(
+                "Album download completed: {}/{} tracks downloaded",
+                album.completed_tracks, album.track_count
+            ));
+            self.album_downloaded.clear();
+        }
+    }
+}
+
+impl ProgressObserver for ConsoleProgress {
+    fn on_progress(&self, event: &ProgressEvent) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match &event.album {
+            Some(album) => state.album_event(event, album),
+            None => state.track_event(event),
+        }
+    }
+}
+
+/// The album a track download belongs to, used to fill in [`AlbumPosition`].
+pub(crate) struct AlbumContext<'a> {
+    /// The position of the track in the album, starting at 1
+    pub(crate) track_position: usize,
+    pub(crate) track_count: usize,
+    /// The number of tracks of the album completed so far, shared by its downloads
+    pub(crate) completed_tracks: &'a AtomicUsize,
+    /// The number of tracks on the disc of the track, if known
+    pub(crate) disc_track_count: Option<i32>,
+}
+
+/// Reports the progress of one track download to an observer.
+pub(crate) struct ProgressReporter<'a> {
+    pub(crate) observer: &'a dyn ProgressObserver,
+    pub(crate) track_id: &'a str,
+    pub(crate) path: &'a str,
+    pub(crate) album: Option<AlbumContext<'a>>,
+}
+
+impl ProgressReporter<'_> {
+    /// Reports a step of the download without byte counts.
+    pub(crate) fn phase(&self, phase: DownloadPhase) {
+        self.report(phase, 0, None);
+    }
+
+    /// Reports the bytes downloaded so far.
+    pub(crate) fn downloading(&self, downloaded: u64, total: Option<u64>) {
+        self.report(DownloadPhase::Downloading, downloaded, total);
+    }
+
+    /// Counts the track as completed in its album, and reports it.
+    pub(crate) fn completed(&self) {
+        if let Some(album) = &self.album {
+            album.completed_tracks.fetch_add(1, SeqCst);
+        }
+        self.phase(DownloadPhase::Completed);
+    }
+
+    fn report(&self, phase: DownloadPhase, downloaded: u64, total: Option<u64>) {
+        self.observer.on_progress(&ProgressEvent {
+            track_id: self.track_id.to_string(),
+            path: self.path.to_string(),
+            phase,
+            downloaded,
+            total,
+            album: self.album.as_ref().map(|album| AlbumPosition {
+                track_position: album.track_position,
+                track_count: album.track_count,
+                completed_tracks: album.completed_tracks.load(SeqCst),
+            }),
+        });
+    }
+}
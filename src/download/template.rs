@@ -0,0 +1,455 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    mem::take,
+    str::{Chars, FromStr},
+};
+
+use crate::{
+    errors::QobuzApiError::{self, InvalidParameterError},
+    models::{Album, Track},
+    utils::sanitize_filename,
+};
+
+/// The placeholders recognized in a [`PathTemplate`].
+const PLACEHOLDERS: [&str; 12] = [
+    "album_artist",
+    "album",
+    "version",
+    "year",
+    "label",
+    "upc",
+    "disc",
+    "track",
+    "title",
+    "artist",
+    "quality",
+    "format",
+];
+
+/// A template for the paths of downloaded files and folders.
+///
+/// A template is a path with `/` separated segments, in which placeholders are replaced
+/// by the values of a [`TemplateValues`]:
+///
+/// - `{name}` inserts a value, where `name` is one of `album_artist`, `album`, `version`,
+///   `year`, `label`, `upc`, `disc`, `track`, `title`, `artist`, `quality` and `format`
+/// - `{name:0N}` pads the value with zeros to `N` characters, as in `{track:02}`. A
+///   missing value is rendered as `N` zeros.
+/// - `[...]` is a conditional part, left out unless every placeholder in it has a value.
+///   It may contain `/`, to add a folder only when its values are known.
+///
+/// Each segment is passed through [`sanitize_filename`] once rendered, so values may
+/// contain characters such as `/` without adding folders. Empty segments are left out.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::template::{PathTemplate, TemplateValues};
+///
+/// let template: PathTemplate = "{album_artist}/{album}[ ({version})]/[CD{disc}/]{track:02}. {title}"
+///     .parse()
+///     .unwrap();
+///
+/// let values = TemplateValues {
+///     album_artist: Some("AC/DC".to_string()),
+///     album: Some("Back in Black".to_string()),
+///     track: Some(1),
+///     title: Some("Hells Bells".to_string()),
+///     ..Default::default()
+/// };
+/// assert_eq!(template.render(&values), "AC_DC/Back in Black/01. Hells Bells");
+///
+/// let unnumbered = TemplateValues {
+///     track: None,
+///     ..values.clone()
+/// };
+/// assert_eq!(template.render(&unnumbered), "AC_DC/Back in Black/00. Hells Bells");
+///
+/// let values = TemplateValues {
+///     version: Some("Deluxe Edition".to_string()),
+///     disc: Some(2),
+///     ..values
+/// };
+/// assert_eq!(
+///     template.render(&values),
+///     "AC_DC/Back in Black (Deluxe Edition)/CD2/01. Hells Bells"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathTemplate {
+    source: String,
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Text(String),
+    Separator,
+    Placeholder { name: &'static str, width: usize },
+    Conditional(Vec<Token>),
+}
+
+impl PathTemplate {
+    /// The default template of the track files of an album, relative to the album folder.
+    ///
+    /// This is the [`DiscLayout::Subfolders`] layout, which puts the tracks of multi-disc
+    /// albums in one folder per disc.
+    pub const DEFAULT_TRACK: &'static str = "[CD{disc}/]{track:02}. {title}";
+
+    /// The default template of album folders, relative to the download folder.
+    pub const DEFAULT_ALBUM: &'static str = "{album_artist}/{album}";
+
+    /// The default template of the track files of a playlist downloaded into one folder.
+    pub const DEFAULT_FLAT_TRACK: &'static str = "{artist} - {title}";
+
+    /// Parses a template.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PathTemplate)` - The parsed template
+    /// * `Err(QobuzApiError)` - If a placeholder is unknown or malformed, or a bracket or
+    ///   brace is not closed
+    pub fn parse(template: &str) -> Result<Self, QobuzApiError> {
+        let mut chars = template.chars();
+        let tokens = parse_tokens(&mut chars, template, false)?;
+
+        Ok(Self {
+            source: template.to_string(),
+            tokens,
+        })
+    }
+
+    /// Returns the template as it was written.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Renders the template into a relative path with `/` separated segments.
+    pub fn render(&self, values: &TemplateValues) -> String {
+        let mut segments = vec![String::new()];
+        render_tokens(&self.tokens, values, &mut segments);
+
+        segments
+            .iter()
+            .map(|segment| sanitize_filename(segment))
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// Preset layouts for the tracks of multi-disc albums.
+///
+/// Track numbers restart on every disc, so the tracks of a multi-disc album need the disc
+/// number in their path to be told apart. Single-disc albums are laid out the same way
+/// whatever the preset.
+///
+/// # Example
+///
+/// ```
+/// use qobuz_api_rust::download::{
+///     DownloadOptions,
+///     template::{DiscLayout, TemplateValues},
+/// };
+///
+/// let options = DownloadOptions {
+///     track_template: DiscLayout::Prefix.track_template(),
+///     ..Default::default()
+/// };
+///
+/// let values = TemplateValues {
+///     disc: Some(2),
+///     track: Some(7),
+///     title: Some("Outro".to_string()),
+///     ..Default::default()
+/// };
+/// assert_eq!(options.track_template.render(&values), "2-07. Outro");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiscLayout {
+    /// One folder per disc, as in `CD2/07. Title`
+    #[default]
+    Subfolders,
+    /// The disc number before the track number, as in `2-07. Title`
+    Prefix,
+    /// No disc number, as in `07. Title`. Tracks of different discs may clash.
+    Flat,
+}
+
+impl DiscLayout {
+    /// Returns the template string of the layout.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Subfolders => PathTemplate::DEFAULT_TRACK,
+            Self::Prefix => "[{disc}-]{track:02}. {title}",
+            Self::Flat => "{track:02}. {title}",
+        }
+    }
+
+    /// Returns the track template of the layout.
+    pub fn track_template(&self) -> PathTemplate {
+        PathTemplate::parse(self.as_str()).expect("the disc layout templates are valid")
+    }
+}
+
+impl FromStr for PathTemplate {
+    type Err = QobuzApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for PathTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.source)
+    }
+}
+
+/// Parses tokens until the end of the template, or the end of the conditional part.
+fn parse_tokens(
+    chars: &mut Chars<'_>,
+    template: &str,
+    in_conditional: bool,
+) -> Result<Vec<Token>, QobuzApiError> {
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+
+    let invalid = |message: &str| InvalidParameterError {
+        message: format!("Invalid path template \"{}\": {}", template, message),
+    };
+
+    while let Some(c) = chars.next() {
+        if matches!(c, '/' | '{' | '[' | ']') && !text.is_empty() {
+            tokens.push(Token::Text(take(&mut text)));
+        }
+
+        match c {
+            '/' => tokens.push(Token::Separator),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err(invalid("unclosed {")),
+                    }
+                }
+
+                let (name, width) = match placeholder.split_once(':') {
+                    Some((name, width)) => {
+                        let width = width
+                            .strip_prefix('0')
+                            .and_then(|w| w.parse::<usize>().ok())
+                            .ok_or_else(|| {
+                                invalid(&format!("invalid padding in {{{}}}", placeholder))
+                            })?;
+                        (name, width)
+                    }
+                    None => (placeholder.as_str(), 0),
+                };
+
+                let name = PLACEHOLDERS
+                    .iter()
+                    .find(|known| **known == name)
+                    .ok_or_else(|| invalid(&format!("unknown placeholder {{{}}}", placeholder)))?;
+                tokens.push(Token::Placeholder { name, width });
+            }
+            '[' => tokens.push(Token::Conditional(parse_tokens(chars, template, true)?)),
+            ']' if in_conditional => return Ok(tokens),
+            ']' => return Err(invalid("unexpected ]")),
+            '}' => return Err(invalid("unexpected }")),
+            c => text.push(c),
+        }
+    }
+
+    if in_conditional {
+        return Err(invalid("unclosed ["));
+    }
+
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+    Ok(tokens)
+}
+
+/// Renders tokens, appending to the last segment and starting new ones at separators.
+fn render_tokens(tokens: &[Token], values: &TemplateValues, segments: &mut Vec<String>) {
+    for token in tokens {
+        match token {
+            Token::Text(text) => push_text(segments, text),
+            Token::Separator => segments.push(String::new()),
+            Token::Placeholder { name, width } => {
+                // A padded placeholder keeps its width, so `{track:02}` renders as `00`
+                let value = values.get(name).unwrap_or_default();
+                push_text(segments, &format!("{:0>width$}", value, width = width));
+            }
+            Token::Conditional(inner) => {
+                if has_all_values(inner, values) {
+                    render_tokens(inner, values, segments);
+                }
+            }
+        }
+    }
+}
+
+fn push_text(segments: &mut [String], text: &str) {
+    if let Some(segment) = segments.last_mut() {
+        segment.push_str(text);
+    }
+}
+
+/// Returns `true` if every placeholder directly in the tokens has a value.
+///
+/// Nested conditional parts are checked when they are rendered.
+fn has_all_values(tokens: &[Token], values: &TemplateValues) -> bool {
+    tokens.iter().all(|token| match token {
+        Token::Placeholder { name, .. } => values.get(name).is_some(),
+        _ => true,
+    })
+}
+
+/// The values of the placeholders of a [`PathTemplate`].
+///
+/// Empty strings are treated as missing values.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateValues {
+    /// The main artist of the album
+    pub album_artist: Option<String>,
+    /// The title of the album
+    pub album: Option<String>,
+    /// The version of the album, such as "Deluxe Edition"
+    pub version: Option<String>,
+    /// The year of the original release
+    pub year: Option<String>,
+    /// The name of the label
+    pub label: Option<String>,
+    /// The UPC of the album
+    pub upc: Option<String>,
+    /// The disc number, set only for albums with several discs
+    pub disc: Option<i32>,
+    /// The track number on its disc
+    pub track: Option<i32>,
+    /// The title of the track
+    pub title: Option<String>,
+    /// The main performer of the track
+    pub artist: Option<String>,
+    /// The audio quality, such as "24-96" for 24-bit 96 kHz, or "320" for MP3
+    pub quality: Option<String>,
+    /// The audio format, "FLAC" or "MP3"
+    pub format: Option<String>,
+}
+
+impl TemplateValues {
+    /// Returns the values describing an album downloaded in the given format.
+    ///
+    /// The album artist and title fall back to "Unknown Artist" and "Unknown Album".
+    pub fn for_album(album: &Album, format_id: &str) -> Self {
+        Self {
+            album_artist: Some(
+                album
+                    .artist
+                    .as_ref()
+                    .and_then(|artist| artist.name.clone())
+                    .unwrap_or_else(|| "Unknown Artist".to_string()),
+            ),
+            album: Some(
+                album
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| "Unknown Album".to_string()),
+            ),
+            version: album.version.clone(),
+            year: album
+                .release_date_original
+                .as_deref()
+                .and_then(|date| date.get(..4))
+                .map(str::to_string),
+            label: album.label.as_ref().and_then(|label| label.name.clone()),
+            upc: album.upc.clone(),
+            quality: quality(
+                format_id,
+                album.maximum_bit_depth,
+                album.maximum_sampling_rate,
+            ),
+            format: format(format_id),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the values describing a track of an album downloaded in the given format.
+    ///
+    /// The disc number is only set when the album has several discs. The title falls back
+    /// to "Track {id}", and the artist to the album artist.
+    pub fn for_track(track: &Track, album: &Album, format_id: &str) -> Self {
+        Self {
+            disc: track
+                .media_number
+                .filter(|_| album.media_count.is_some_and(|count| count > 1)),
+            track: track.track_number,
+            title: Some(
+                track
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| format!("Track {}", track.id.unwrap_or_default())),
+            ),
+            artist: track
+                .performer
+                .as_ref()
+                .and_then(|performer| performer.name.clone())
+                .or_else(|| album.artist.as_ref().and_then(|artist| artist.name.clone())),
+            quality: quality(
+                format_id,
+                track.maximum_bit_depth.or(album.maximum_bit_depth),
+                track.maximum_sampling_rate.or(album.maximum_sampling_rate),
+            ),
+            ..Self::for_album(album, format_id)
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<String> {
+        let value = match name {
+            "album_artist" => self.album_artist.clone(),
+            "album" => self.album.clone(),
+            "version" => self.version.clone(),
+            "year" => self.year.clone(),
+            "label" => self.label.clone(),
+            "upc" => self.upc.clone(),
+            "disc" => self.disc.map(|disc| disc.to_string()),
+            "track" => self.track.map(|track| track.to_string()),
+            "title" => self.title.clone(),
+            "artist" => self.artist.clone(),
+            "quality" => self.quality.clone(),
+            "format" => self.format.clone(),
+            _ => None,
+        };
+
+        value.filter(|value| !value.is_empty())
+    }
+}
+
+/// Describes the quality of a format, using the bit depth and sampling rate for hi-res.
+fn quality(format_id: &str, bit_depth: Option<f64>, sampling_rate: Option<f64>) -> Option<String> {
+    match format_id {
+        "5" => Some("320".to_string()),
+        "6" => Some("16-44.1".to_string()),
+        _ => match (bit_depth, sampling_rate) {
+            // Format 7 is delivered at up to 96 kHz, whatever the source
+            (Some(bit_depth), Some(sampling_rate)) if format_id == "7" => {
+                Some(format!("{}-{}", bit_depth, sampling_rate.min(96.0)))
+            }
+            (Some(bit_depth), Some(sampling_rate)) => {
+                Some(format!("{}-{}", bit_depth, sampling_rate))
+            }
+            _ => None,
+        },
+    }
+}
+
+/// Names the audio format of a format ID.
+fn format(format_id: &str) -> Option<String> {
+    match format_id {
+        "5" => Some("MP3".to_string()),
+        _ => Some("FLAC".to_string()),
+    }
+}
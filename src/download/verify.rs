@@ -0,0 +1,308 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::metadata,
+    io::Read,
+    path::Path,
+};
+
+use {
+    claxon::FlacReader,
+    lofty::{
+        file::{AudioFile, FileType, TaggedFileExt},
+        read_from_path,
+    },
+    md5::Context,
+};
+
+use crate::errors::QobuzApiError::{self, DownloadError};
+
+/// How thoroughly a downloaded file is checked before its metadata is embedded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verification {
+    /// Do not check the downloaded file.
+    Skip,
+    /// Check the size of the file, parse it, and for FLAC files check the number of
+    /// samples in STREAMINFO against the duration of the track.
+    #[default]
+    Structure,
+    /// Run the [`Structure`](Self::Structure) checks, and for FLAC files also decode the
+    /// audio and compare it with the MD5 signature in STREAMINFO.
+    Decode,
+}
+
+/// The audio properties read from the STREAMINFO block of a FLAC file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlacStreamInfo {
+    /// The sample rate in Hz
+    pub sample_rate: u32,
+    /// The number of channels
+    pub channels: u32,
+    /// The number of bits per sample
+    pub bits_per_sample: u32,
+    /// The number of samples per channel, if the encoder recorded it
+    pub total_samples: Option<u64>,
+    /// The MD5 signature of the decoded audio, all zeros if the encoder did not compute it
+    pub md5: [u8; 16],
+}
+
+impl FlacStreamInfo {
+    /// The duration of the audio in seconds, if the number of samples is known.
+    pub fn duration_secs(&self) -> Option<f64> {
+        self.total_samples
+            .filter(|_| self.sample_rate > 0)
+            .map(|samples| samples as f64 / self.sample_rate as f64)
+    }
+}
+
+/// A problem found while verifying a downloaded file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerificationProblem {
+    /// The file size differs from the `Content-Length` announced by the server.
+    SizeMismatch {
+        /// The number of bytes announced by the server
+        expected: u64,
+        /// The number of bytes in the file
+        actual: u64,
+    },
+    /// The file could not be parsed as an audio file.
+    Unreadable(String),
+    /// The duration of the audio differs from the duration of the track.
+    DurationMismatch {
+        /// The duration of the track in seconds, according to Qobuz
+        expected_secs: i64,
+        /// The duration of the audio in seconds
+        actual_secs: f64,
+    },
+    /// The decoded audio does not match the MD5 signature in STREAMINFO.
+    Md5Mismatch,
+}
+
+impl Display for VerificationProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::SizeMismatch { expected, actual } => {
+                write!(f, "file has {} bytes, expected {}", actual, expected)
+            }
+            Self::Unreadable(message) => write!(f, "file could not be parsed: {}", message),
+            Self::DurationMismatch {
+                expected_secs,
+                actual_secs,
+            } => write!(
+                f,
+                "audio lasts {:.1} seconds, expected {} seconds",
+                actual_secs, expected_secs
+            ),
+            Self::Md5Mismatch => write!(f, "decoded audio does not match the STREAMINFO MD5"),
+        }
+    }
+}
+
+/// The result of verifying a downloaded file.
+///
+/// A report lists every problem found rather than stopping at the first one. Use
+/// [`is_valid`](Self::is_valid) to check the outcome, or [`into_result`](Self::into_result)
+/// to turn a failed verification into a [`DownloadError`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationReport {
+    /// The size of the file in bytes
+    pub size: u64,
+    /// The size announced by the server, if any
+    pub expected_size: Option<u64>,
+    /// The file type detected when parsing the file
+    pub file_type: Option<FileType>,
+    /// The duration of the audio in seconds, as read by lofty
+    pub duration_secs: Option<f64>,
+    /// The STREAMINFO block, for FLAC files
+    pub flac: Option<FlacStreamInfo>,
+    /// Whether the decoded audio matched the STREAMINFO MD5, if it was checked
+    ///
+    /// This is `None` when the audio was not decoded, or when the encoder did not record
+    /// an MD5 signature.
+    pub md5_verified: Option<bool>,
+    /// The problems found, empty if the file is valid
+    pub problems: Vec<VerificationProblem>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if no problem was found.
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Returns the report if the file is valid, or a `DownloadError` listing the problems.
+    pub fn into_result(self) -> Result<Self, QobuzApiError> {
+        if self.is_valid() {
+            return Ok(self);
+        }
+
+        let problems: Vec<String> = self.problems.iter().map(|p| p.to_string()).collect();
+        Err(DownloadError {
+            message: format!("Verification failed: {}", problems.join("; ")),
+        })
+    }
+}
+
+/// Verifies that a downloaded audio file is complete and readable.
+///
+/// The checks run are:
+///
+/// - the file size is compared with `expected_size`, the `Content-Length` of the download
+/// - the file is parsed with lofty
+/// - for FLAC files, the number of samples in STREAMINFO is compared with
+///   `expected_duration`, allowing one second of rounding
+/// - for FLAC files with [`Verification::Decode`], the audio is decoded and compared with
+///   the MD5 signature in STREAMINFO
+///
+/// # Arguments
+///
+/// * `path` - The path of the downloaded file
+/// * `expected_size` - The number of bytes announced by the server, if known
+/// * `expected_duration` - The duration of the track in seconds, if known
+/// * `level` - How thoroughly to check the file
+///
+/// # Returns
+///
+/// * `Ok(VerificationReport)` - The report, which lists the problems found, if any
+/// * `Err(QobuzApiError)` - If the size of the file cannot be read
+///
+/// # Example
+///
+/// ```no_run
+/// use qobuz_api_rust::download::verify::{Verification, verify_file};
+///
+/// # fn example() -> Result<(), qobuz_api_rust::QobuzApiError> {
+/// let report = verify_file("downloads/01. Track.flac", None, Some(215), Verification::Decode)?;
+/// if !report.is_valid() {
+///     for problem in &report.problems {
+///         println!("{}", problem);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_file<P: AsRef<Path>>(
+    path: P,
+    expected_size: Option<u64>,
+    expected_duration: Option<i64>,
+    level: Verification,
+) -> Result<VerificationReport, QobuzApiError> {
+    let path = path.as_ref();
+    let size = metadata(path)
+        .map_err(|e| DownloadError {
+            message: format!("Failed to read downloaded file: {}", e),
+        })?
+        .len();
+
+    let mut report = VerificationReport {
+        size,
+        expected_size,
+        file_type: None,
+        duration_secs: None,
+        flac: None,
+        md5_verified: None,
+        problems: Vec::new(),
+    };
+
+    if level == Verification::Skip {
+        return Ok(report);
+    }
+
+    if let Some(expected) = expected_size
+        && expected != size
+    {
+        report.problems.push(VerificationProblem::SizeMismatch {
+            expected,
+            actual: size,
+        });
+    }
+
+    let tagged_file = match read_from_path(path) {
+        Ok(tagged_file) => tagged_file,
+        Err(e) => {
+            report
+                .problems
+                .push(VerificationProblem::Unreadable(e.to_string()));
+            return Ok(report);
+        }
+    };
+    report.file_type = Some(tagged_file.file_type());
+    report.duration_secs = Some(tagged_file.properties().duration().as_secs_f64());
+
+    if tagged_file.file_type() == FileType::Flac {
+        verify_flac(path, expected_duration, level, &mut report);
+    }
+
+    Ok(report)
+}
+
+/// Runs the FLAC specific checks, recording the results in the report.
+fn verify_flac(
+    path: &Path,
+    expected_duration: Option<i64>,
+    level: Verification,
+    report: &mut VerificationReport,
+) {
+    let mut reader = match FlacReader::open(path) {
+        Ok(reader) => reader,
+        Err(e) => {
+            report
+                .problems
+                .push(VerificationProblem::Unreadable(e.to_string()));
+            return;
+        }
+    };
+
+    let streaminfo = reader.streaminfo();
+    let info = FlacStreamInfo {
+        sample_rate: streaminfo.sample_rate,
+        channels: streaminfo.channels,
+        bits_per_sample: streaminfo.bits_per_sample,
+        total_samples: streaminfo.samples,
+        md5: streaminfo.md5sum,
+    };
+
+    // Qobuz rounds durations to whole seconds
+    if let Some(expected_secs) = expected_duration
+        && let Some(actual_secs) = info.duration_secs()
+        && (actual_secs - expected_secs as f64).abs() > 1.0
+    {
+        report.problems.push(VerificationProblem::DurationMismatch {
+            expected_secs,
+            actual_secs,
+        });
+    }
+
+    if level == Verification::Decode && info.md5 != [0; 16] {
+        match decoded_md5(&mut reader, info.bits_per_sample) {
+            Ok(md5) => {
+                report.md5_verified = Some(md5 == info.md5);
+                if md5 != info.md5 {
+                    report.problems.push(VerificationProblem::Md5Mismatch);
+                }
+            }
+            Err(e) => report
+                .problems
+                .push(VerificationProblem::Unreadable(e.to_string())),
+        }
+    }
+
+    report.flac = Some(info);
+}
+
+/// Decodes the audio of a FLAC file and computes its MD5 signature.
+///
+/// As in the FLAC format, the signature covers the interleaved samples, each stored as a
+/// little-endian signed integer of the smallest whole number of bytes holding it.
+fn decoded_md5<R: Read>(
+    reader: &mut FlacReader<R>,
+    bits_per_sample: u32,
+) -> Result<[u8; 16], claxon::Error> {
+    let bytes_per_sample = bits_per_sample.div_ceil(8) as usize;
+    let mut context = Context::new();
+
+    for sample in reader.samples() {
+        context.consume(&sample?.to_le_bytes()[..bytes_per_sample]);
+    }
+
+    Ok(context.finalize().0)
+}
@@ -52,6 +52,7 @@
 /// ## Modules
 ///
 /// - [api](api/index.html): Core API functionality and service implementation
+/// - [download](download/index.html): Download options for tracks and albums
 /// - [errors](errors/index.html): Custom error types for the library
 /// - [metadata](metadata/index.html): Metadata extraction and embedding utilities
 /// - [models](models/index.html): Data models for API responses
@@ -61,6 +62,11 @@
 ///
 /// This library is distributed under the GPL-3.0-or-later license.
 pub mod api;
+/// Download configuration for tracks and albums.
+///
+/// This module provides the options controlling how tracks and albums are downloaded,
+/// including which metadata is embedded and the intent used when requesting file URLs.
+pub mod download;
 /// Error types for the Qobuz API Rust library.
 ///
 /// This module defines custom error types that can occur when using the Qobuz API library.
@@ -1,10 +1,21 @@
-use std::io::stdin;
+use std::{io::stdin, sync::Arc};
 
 use {dotenvy::dotenv, tokio::main};
 
 use qobuz_api_rust::{
-    api::service::QobuzApiService, errors::QobuzApiError, metadata::MetadataConfig,
-    utils::sanitize_filename,
+    api::{
+        content::{
+            links::{QobuzLink, QobuzResource},
+            playlists::PlaylistLayout,
+        },
+        service::QobuzApiService,
+    },
+    download::{
+        DownloadOptions, library::Library, outcome::extension_for_format_id,
+        template::TemplateValues,
+    },
+    errors::QobuzApiError,
+    models::Album,
 };
 
 /// The main entry point for the Qobuz API Rust Client CLI application.
@@ -39,7 +50,18 @@ async fn main() -> Result<(), QobuzApiError> {
         service.app_id
     );
 
-    let config = MetadataConfig::default();
+    // Record the downloads, so that tracks already in `downloads` are not fetched again
+    let library = match Library::open("downloads") {
+        Ok(library) => Some(Arc::new(library)),
+        Err(e) => {
+            println!("Failed to open the download manifest: {}", e);
+            None
+        }
+    };
+    let options = DownloadOptions {
+        library,
+        ..Default::default()
+    };
 
     // Try to authenticate using environment variables
     // This will automatically try different authentication methods based on available environment variables:
@@ -69,11 +91,40 @@ async fn main() -> Result<(), QobuzApiError> {
     }
 
     loop {
-        println!("\nWhat do you want to search for? (e.g., 'Miles Davis')");
+        println!("\nWhat do you want to search for? (e.g., 'Miles Davis', or paste a Qobuz link)");
         let mut query = String::new();
         stdin().read_line(&mut query).expect("Failed to read line");
         let query = query.trim();
 
+        // Download linked albums, tracks and playlists directly
+        if let Ok(link) = QobuzLink::parse(query) {
+            match service.resolve(&link).await {
+                Ok(QobuzResource::Album(album)) => {
+                    if let Some(album_id) = &album.id {
+                        let quality = choose_quality()?;
+                        download_album(&service, album_id, &album, &quality, &options).await;
+                    }
+                }
+                Ok(QobuzResource::Track(_)) => {
+                    let quality = choose_quality()?;
+                    download_track(&service, link.id(), &quality, &options).await?;
+                }
+                Ok(QobuzResource::Artist(artist)) => println!(
+                    "Artist links can't be downloaded directly, search for '{}' instead",
+                    artist.name.as_deref().unwrap_or("the artist")
+                ),
+                Ok(QobuzResource::Playlist(_)) => {
+                    let quality = choose_quality()?;
+                    download_playlist(&service, link.id(), &quality, &options).await;
+                }
+                Ok(QobuzResource::Label(_)) => {
+                    println!("Only album, track and playlist links can be downloaded")
+                }
+                Err(e) => println!("Failed to resolve link: {}", e),
+            }
+            continue;
+        }
+
         println!();
         println!("Search for an a) album or t) track?");
         let mut search_type = String::new();
@@ -118,40 +169,14 @@ async fn main() -> Result<(), QobuzApiError> {
                             let selected_album = &items[album_index - 1];
                             if let Some(album_id) = &selected_album.id {
                                 let quality = choose_quality()?;
-
-                                // Use the same artist/album naming structure as in download_album
-                                let album_artist_name =
-                                    if let Some(ref album_artist) = selected_album.artist {
-                                        album_artist
-                                            .name
-                                            .as_ref()
-                                            .unwrap_or(&"Unknown Artist".to_string())
-                                            .clone()
-                                    } else {
-                                        "Unknown Artist".to_string()
-                                    };
-
-                                let album_title = selected_album
-                                    .title
-                                    .as_ref()
-                                    .unwrap_or(&"Unknown Album".to_string())
-                                    .clone();
-
-                                // Create the directory structure: [Albuminterpret]/[Album]
-                                let album_artist_dir = sanitize_filename(&album_artist_name);
-                                let album_title_dir = sanitize_filename(&album_title);
-                                let album_path =
-                                    format!("downloads/{}/{}", album_artist_dir, album_title_dir);
-
-                                println!();
-                                println!("Downloading album...");
-                                match service
-                                    .download_album(album_id, &quality, &album_path, &config)
-                                    .await
-                                {
-                                    Ok(_) => println!("Album downloaded successfully!"),
-                                    Err(e) => println!("Failed to download album: {}", e),
-                                }
+                                download_album(
+                                    &service,
+                                    album_id,
+                                    selected_album,
+                                    &quality,
+                                    &options,
+                                )
+                                .await;
                             }
                         }
                     }
@@ -197,111 +222,8 @@ async fn main() -> Result<(), QobuzApiError> {
                             let selected_track = &items[track_index - 1];
                             if let Some(track_id) = selected_track.id {
                                 let quality = choose_quality()?;
-                                let extension = match quality.as_str() {
-                                    "5" => "mp3",               // MP3 320
-                                    "6" | "7" | "27" => "flac", // FLAC formats
-                                    _ => "flac",                // default to flac
-                                };
-
-                                // Get the track details to create proper naming
-                                let track_details =
-                                    service.get_track(&track_id.to_string(), None).await?;
-
-                                // Get album details for artist and album info
-                                let album_details = if let Some(ref track_album) =
-                                    track_details.album
-                                {
-                                    track_album.as_ref().clone()
-                                } else {
-                                    // If no album info available, use basic naming
-                                    println!("Warning: No album information available for track");
-                                    let filename = format!(
-                                        "downloads/{}.{}",
-                                        track_details.title.as_deref().unwrap_or("track"),
-                                        extension
-                                    );
-                                    match service
-                                        .download_track(
-                                            &track_id.to_string(),
-                                            &quality,
-                                            &filename,
-                                            &config,
-                                        )
-                                        .await
-                                    {
-                                        Ok(_) => {
-                                            println!();
-                                            println!("Track downloaded successfully!");
-                                        }
-                                        Err(e) => {
-                                            println!();
-                                            println!("Failed to download track: {}", e)
-                                        }
-                                    }
-                                    continue;
-                                };
-
-                                // Get album artist name
-                                let album_artist_name =
-                                    if let Some(ref album_artist) = album_details.artist {
-                                        album_artist
-                                            .name
-                                            .as_ref()
-                                            .unwrap_or(&"Unknown Artist".to_string())
-                                            .clone()
-                                    } else {
-                                        "Unknown Artist".to_string()
-                                    };
-
-                                // Get album title
-                                let album_title = album_details
-                                    .title
-                                    .as_ref()
-                                    .unwrap_or(&"Unknown Album".to_string())
-                                    .clone();
-
-                                // Get track number and title
-                                let track_number = track_details.track_number.unwrap_or(0);
-                                let track_title = track_details
-                                    .title
-                                    .as_ref()
-                                    .unwrap_or(&format!("Track {}", track_id))
-                                    .clone();
-
-                                // Create the directory structure: [Albuminterpret]/[Album]
-                                let album_artist_dir = sanitize_filename(&album_artist_name);
-                                let album_title_dir = sanitize_filename(&album_title);
-                                let album_dir =
-                                    format!("downloads/{}/{}", album_artist_dir, album_title_dir);
-
-                                // Create filename following MusicBrainz Picard style: [Titelnr.]. [Titel]
-                                let track_filename =
-                                    format!("{:02}. {}", track_number, track_title);
-                                let sanitized_filename = sanitize_filename(&track_filename);
-                                let filename =
-                                    format!("{}/{}.{}", album_dir, sanitized_filename, extension);
-
-                                println!();
-                                println!("Downloading track...");
-                                println!();
-                                match service
-                                    .download_track(
-                                        &track_id.to_string(),
-                                        &quality,
-                                        &filename,
-                                        &config,
-                                    )
-                                    .await
-                                {
-                                    Ok(_) => {
-                                        println!();
-                                        println!("Track downloaded successfully!");
-                                    }
-                                    Err(e) => {
-                                        println!();
-                                        println!("Failed to download track: {}", e)
-                                    }
-                                }
+                                download_track(&service, &track_id.to_string(), &quality, &options)
+                                    .await?;
                             }
                         }
                     }
@@ -312,6 +234,143 @@ async fn main() -> Result<(), QobuzApiError> {
     }
 }
 
+/// Downloads an album into `downloads/[Album Artist]/[Album]`.
+///
+/// Errors are reported to the console rather than returned, so that the interactive
+/// loop can continue after a failed download.
+async fn download_album(
+    service: &QobuzApiService,
+    album_id: &str,
+    album: &Album,
+    quality: &str,
+    options: &DownloadOptions,
+) {
+    let album_path = format!(
+        "downloads/{}",
+        options
+            .album_template
+            .render(&TemplateValues::for_album(album, quality))
+    );
+
+    println!();
+    println!("Downloading album...");
+    match service
+        .download_album(album_id, quality, &album_path, options)
+        .await
+    {
+        Ok(_) => println!("Album downloaded successfully!"),
+        Err(e) => println!("Failed to download album: {}", e),
+    }
+}
+
+/// Downloads a playlist into `downloads`, with each track in the folder of its album and
+/// the playlist file next to the album folders.
+///
+/// Errors are reported to the console rather than returned, so that the interactive
+/// loop can continue after a failed download.
+async fn download_playlist(
+    service: &QobuzApiService,
+    playlist_id: &str,
+    quality: &str,
+    options: &DownloadOptions,
+) {
+    println!();
+    println!("Downloading playlist...");
+    match service
+        .download_playlist(
+            playlist_id,
+            quality,
+            "downloads",
+            PlaylistLayout::Albums,
+            options,
+        )
+        .await
+    {
+        Ok(download) => {
+            for (track_id, error) in download.failed() {
+                println!("Failed to download track {}: {}", track_id, error);
+            }
+            println!("Playlist saved to {}", download.playlist_path);
+        }
+        Err(e) => println!("Failed to download playlist: {}", e),
+    }
+}
+
+/// Downloads a track into `downloads/[Album Artist]/[Album]/[Track Number]. [Title]`.
+///
+/// Download errors are reported to the console rather than returned, so that the
+/// interactive loop can continue after a failed download.
+///
+/// # Errors
+///
+/// Returns a `QobuzApiError` if the track details cannot be retrieved.
+async fn download_track(
+    service: &QobuzApiService,
+    track_id: &str,
+    quality: &str,
+    options: &DownloadOptions,
+) -> Result<(), QobuzApiError> {
+    let extension = extension_for_format_id(quality);
+
+    // Get the track details to create proper naming
+    let track_details = service.get_track(track_id, None).await?;
+
+    // Get album details for artist and album info
+    let album_details = if let Some(ref track_album) = track_details.album {
+        track_album.as_ref().clone()
+    } else {
+        // If no album info available, use basic naming
+        println!("Warning: No album information available for track");
+        let filename = format!(
+            "downloads/{}.{}",
+            track_details.title.as_deref().unwrap_or("track"),
+            extension
+        );
+        match service
+            .download_track(track_id, quality, &filename, options)
+            .await
+        {
+            Ok(outcome) => {
+                println!();
+                println!("Track downloaded to {}", outcome.path);
+            }
+            Err(e) => {
+                println!();
+                println!("Failed to download track: {}", e)
+            }
+        }
+        return Ok(());
+    };
+
+    // Name the folder and file after the album and track templates
+    let values = TemplateValues::for_track(&track_details, &album_details, quality);
+    let filename = format!(
+        "downloads/{}/{}.{}",
+        options.album_template.render(&values),
+        options.track_template.render(&values),
+        extension
+    );
+
+    println!();
+    println!("Downloading track...");
+    println!();
+    match service
+        .download_track(track_id, quality, &filename, options)
+        .await
+    {
+        Ok(outcome) => {
+            println!();
+            println!("Track downloaded to {}", outcome.path);
+        }
+        Err(e) => {
+            println!();
+            println!("Failed to download track: {}", e)
+        }
+    }
+
+    Ok(())
+}
+
 /// Presents an interactive quality selection menu to the user and returns the selected quality format ID.
 ///
 /// The function displays available quality options and prompts the user to select one.
@@ -25,6 +25,11 @@ pub mod core;
 /// This module contains the Credential struct which represents comprehensive user
 /// credential information including personal details, account settings, and feature availability.
 pub mod credential;
+/// Discovery models for editorial listings and the genre hierarchy
+///
+/// This module contains models for featured album and playlist listings, such as
+/// new releases or editor picks, and for navigating the genre tree.
+pub mod discovery;
 /// Metadata models for images, audio info, genres, labels, and other metadata
 ///
 /// This module contains various models for handling metadata such as images, audio information,
@@ -35,6 +40,11 @@ pub mod metadata;
 /// This module contains the Playlist struct which represents a playlist with details about
 /// its content, owner, creation date, and various properties.
 pub mod playlist;
+/// Purchase models containing the albums and tracks bought by a user
+///
+/// This module contains the UserPurchases struct which represents the purchased
+/// albums and tracks of the authenticated user.
+pub mod purchase;
 /// Release models containing information about music releases
 ///
 /// This module contains models for music releases including release details, tracks,
@@ -69,14 +79,18 @@ pub mod user_simple;
 pub use {
     album::Album,
     article::{Article, Author, Biography, Story},
-    artist::Artist,
+    artist::{Artist, SimilarArtists},
     core::{Login, QobuzApiStatusResponse, deserialize_code},
     credential::Credential,
-    metadata::{Area, AudioInfo, Award, Focus, Genre, GenreTag, Goody, Image, Label, Period, Tag},
-    playlist::Playlist,
+    discovery::{FeaturedAlbums, FeaturedPlaylists, GenreList},
+    metadata::{
+        Area, AudioInfo, Award, Focus, Genre, GenreTag, Goody, Image, Label, LabelList, Period, Tag,
+    },
+    playlist::{Playlist, UserPlaylists},
+    purchase::UserPurchases,
     release::{
-        FileUrl, Release, ReleaseArtist, ReleaseAudioInfo, ReleasePhysicalSupport, ReleaseRights,
-        ReleaseTrack, ReleaseTrackList, ReleasesList,
+        FileUrl, FileUrlRestriction, Release, ReleaseArtist, ReleaseAudioInfo,
+        ReleasePhysicalSupport, ReleaseRights, ReleaseTrack, ReleaseTrackList, ReleasesList,
     },
     search::{
         AlbumsSameArtist, ItemSearchResult, MostPopular, MostPopularContent, SearchResult,
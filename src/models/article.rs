@@ -36,6 +36,10 @@ pub struct Article {
     #[serde(rename = "description_short")]
     pub description_short: Option<String>,
 
+    /// Full HTML content of the article
+    #[serde(rename = "content")]
+    pub content: Option<String>,
+
     /// URL to the article
     #[serde(rename = "url")]
     pub url: Option<String>,
@@ -147,6 +151,10 @@ pub struct Story {
     #[serde(rename = "description_short")]
     pub description_short: Option<String>,
 
+    /// Full HTML content of the story
+    #[serde(rename = "content")]
+    pub content: Option<String>,
+
     /// List of authors who wrote the story
     #[serde(rename = "authors")]
     pub authors: Option<Vec<Author>>,
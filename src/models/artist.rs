@@ -3,7 +3,7 @@ use {
     serde_json::Value,
 };
 
-use crate::models::{Album, Biography, Image, ItemSearchResult, Playlist};
+use crate::models::{Album, Biography, Image, ItemSearchResult, Playlist, Track};
 
 /// Artist model representing an artist on the Qobuz platform
 ///
@@ -87,4 +87,36 @@ pub struct Artist {
     /// Additional information about the artist as raw JSON value
     #[serde(rename = "information")]
     pub information: Option<Value>,
+
+    /// Top tracks of the artist (only set when requested with `extra=tracks`)
+    #[serde(rename = "tracks")]
+    pub tracks: Option<ItemSearchResult<Box<Track>>>,
+}
+
+/// Similar artists model containing artists related to an artist
+///
+/// This struct represents the response of the similar artists endpoint, containing
+/// a paginated list of artists similar to the requested one.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{Artist, ItemSearchResult, SimilarArtists};
+///
+/// let similar = SimilarArtists {
+///     artists: Some(ItemSearchResult {
+///         items: Some(vec![Artist {
+///             id: Some(67890),
+///             name: Some("Related Artist".to_string()),
+///             ..Default::default()
+///         }]),
+///         ..Default::default()
+///     }),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SimilarArtists {
+    /// Paginated list of similar artists
+    #[serde(rename = "artists")]
+    pub artists: Option<ItemSearchResult<Artist>>,
 }
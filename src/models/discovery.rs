@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Album, Genre, ItemSearchResult, Playlist};
+
+/// Featured albums model containing an editorial album listing
+///
+/// This struct represents the response of the featured albums endpoint, such as
+/// new releases, press awards, best sellers or editor picks.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{Album, FeaturedAlbums, ItemSearchResult};
+///
+/// let featured = FeaturedAlbums {
+///     albums: Some(ItemSearchResult::<Album>::default()),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeaturedAlbums {
+    /// Paginated list of featured albums
+    #[serde(rename = "albums")]
+    pub albums: Option<ItemSearchResult<Album>>,
+}
+
+/// Featured playlists model containing an editorial playlist listing
+///
+/// This struct represents the response of the featured playlists endpoint, such as
+/// editor picks or the most recently created public playlists.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{FeaturedPlaylists, ItemSearchResult, Playlist};
+///
+/// let featured = FeaturedPlaylists {
+///     playlists: Some(ItemSearchResult::<Playlist>::default()),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FeaturedPlaylists {
+    /// Paginated list of featured playlists
+    #[serde(rename = "playlists")]
+    pub playlists: Option<ItemSearchResult<Playlist>>,
+}
+
+/// Genre list model containing one level of the genre hierarchy
+///
+/// This struct represents the response of the genre listing endpoint. When the
+/// children of a genre are requested, `parent` contains the genre they belong to.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{Genre, GenreList, ItemSearchResult};
+///
+/// let genre_list = GenreList {
+///     genres: Some(ItemSearchResult {
+///         items: Some(vec![Genre {
+///             id: Some(80),
+///             name: Some("Jazz".to_string()),
+///             ..Default::default()
+///         }]),
+///         ..Default::default()
+///     }),
+///     parent: None,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GenreList {
+    /// Paginated list of genres
+    #[serde(rename = "genres")]
+    pub genres: Option<ItemSearchResult<Genre>>,
+
+    /// Parent genre of the listed genres (not set for top-level genres)
+    #[serde(rename = "parent")]
+    pub parent: Option<Genre>,
+}
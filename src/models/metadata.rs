@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::{Album, ItemSearchResult};
+
 /// Image model containing URLs for different sizes of an image
 ///
 /// This struct provides URLs for various sizes of an image, commonly used for
@@ -116,6 +118,8 @@ pub struct Genre {
 /// Label model containing information about a record label
 ///
 /// This struct represents a record label with its identification, name, and slug.
+/// When the label is requested with its albums, the `albums` field contains a
+/// paginated list of the label's releases.
 ///
 /// # Examples
 ///
@@ -126,6 +130,8 @@ pub struct Genre {
 ///     id: Some(456),
 ///     name: Some("Example Records".to_string()),
 ///     slug: Some("example-records".to_string()),
+///     albums_count: Some(1200),
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -141,6 +147,51 @@ pub struct Label {
     /// URL-friendly slug for the label
     #[serde(rename = "slug")]
     pub slug: Option<String>,
+
+    /// Identifier of the supplier distributing the label
+    #[serde(rename = "supplier_id")]
+    pub supplier_id: Option<i32>,
+
+    /// Total number of albums released by the label
+    #[serde(rename = "albums_count")]
+    pub albums_count: Option<i32>,
+
+    /// Description of the label
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+
+    /// Albums released by the label (only set when requested with `extra=albums`)
+    #[serde(rename = "albums")]
+    pub albums: Option<ItemSearchResult<Album>>,
+}
+
+/// Label list model containing a paginated list of labels
+///
+/// This struct represents the response of the label listing and label search
+/// endpoints.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{ItemSearchResult, Label, LabelList};
+///
+/// let label_list = LabelList {
+///     labels: Some(ItemSearchResult {
+///         items: Some(vec![Label {
+///             id: Some(456),
+///             name: Some("Example Records".to_string()),
+///             ..Default::default()
+///         }]),
+///         total: Some(1),
+///         ..Default::default()
+///     }),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LabelList {
+    /// Paginated list of labels
+    #[serde(rename = "labels")]
+    pub labels: Option<ItemSearchResult<Label>>,
 }
 
 /// Tag model containing information about a tag
@@ -147,3 +147,29 @@ pub struct Playlist {
     #[serde(rename = "stores")]
     pub stores: Option<Vec<String>>,
 }
+
+/// User playlists model containing the playlists of a user
+///
+/// This struct represents the response of the user playlists endpoint, containing
+/// the user the playlists belong to and a paginated list of their playlists.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{ItemSearchResult, Playlist, UserPlaylists};
+///
+/// let user_playlists = UserPlaylists {
+///     user: None,
+///     playlists: Some(ItemSearchResult::<Playlist>::default()),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UserPlaylists {
+    /// User who owns the playlists
+    #[serde(rename = "user")]
+    pub user: Option<User>,
+
+    /// Search results for the user's playlists
+    #[serde(rename = "playlists")]
+    pub playlists: Option<ItemSearchResult<PlaylistModel>>,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Album, ItemSearchResult, Track};
+
+/// User purchases model containing the albums and tracks bought by a user
+///
+/// This struct represents the response of the user purchases endpoint. Depending on
+/// the requested purchase type, either the albums or the tracks list is set.
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{Album, ItemSearchResult, UserPurchases};
+///
+/// let purchases = UserPurchases {
+///     albums: Some(ItemSearchResult::<Album>::default()),
+///     tracks: None,
+/// };
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UserPurchases {
+    /// Paginated list of purchased albums
+    #[serde(rename = "albums")]
+    pub albums: Option<ItemSearchResult<Album>>,
+
+    /// Paginated list of individually purchased tracks
+    #[serde(rename = "tracks")]
+    pub tracks: Option<ItemSearchResult<Track>>,
+}
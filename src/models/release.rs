@@ -61,6 +61,55 @@ pub struct FileUrl {
     /// Code providing additional information about the file URL
     #[serde(rename = "code")]
     pub code: Option<String>,
+
+    /// Whether the URL is for a 30-second sample instead of the full track
+    #[serde(rename = "sample")]
+    pub sample: Option<bool>,
+
+    /// Restrictions explaining why the file differs from the requested one
+    #[serde(rename = "restrictions")]
+    pub restrictions: Option<Vec<FileUrlRestriction>>,
+}
+
+impl FileUrl {
+    /// Returns `true` if the URL is for a sample rather than the full track.
+    pub fn is_sample(&self) -> bool {
+        self.sample.unwrap_or(false)
+    }
+
+    /// Returns the codes of the restrictions of the file, such as
+    /// "FormatRestrictedByFormatAvailability".
+    pub fn restriction_codes(&self) -> Vec<String> {
+        self.restrictions
+            .iter()
+            .flatten()
+            .filter_map(|restriction| restriction.code.clone())
+            .collect()
+    }
+}
+
+/// Restriction model explaining why a file URL differs from the requested file
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::models::{FileUrl, FileUrlRestriction};
+///
+/// let file_url = FileUrl {
+///     format_id: Some(6),
+///     restrictions: Some(vec![FileUrlRestriction {
+///         code: Some("FormatRestrictedByFormatAvailability".to_string()),
+///     }]),
+///     ..Default::default()
+/// };
+/// assert_eq!(file_url.restriction_codes(), ["FormatRestrictedByFormatAvailability"]);
+/// assert!(!file_url.is_sample());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FileUrlRestriction {
+    /// Code of the restriction, such as "UserUncredentialed" or "TrackRestrictedByPurchaseCredentials"
+    #[serde(rename = "code")]
+    pub code: Option<String>,
 }
 
 /// Releases list model containing a list of releases
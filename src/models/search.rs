@@ -1,4 +1,7 @@
-use serde::{Deserialize, Serialize};
+use {
+    serde::{Deserialize, Serialize},
+    serde_json::{Map, Value},
+};
 
 use crate::models::{
     Album, Article, Artist, MostPopular as MostPopularModel, Playlist, Story, Track, User,
@@ -146,7 +149,9 @@ pub struct MostPopular {
 
 /// Most popular content model containing type information
 ///
-/// This struct represents the content type for most popular items.
+/// This struct represents the content of a most popular item. Besides the type, it keeps
+/// every other field of the item, so that it can be read as the album, track, artist or
+/// playlist indicated by the type of the enclosing [`MostPopular`].
 ///
 /// # Examples
 ///
@@ -155,6 +160,7 @@ pub struct MostPopular {
 ///
 /// let content = MostPopularContent {
 ///     type_field: Some("track".to_string()),
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -162,6 +168,10 @@ pub struct MostPopularContent {
     /// Type of the content
     #[serde(rename = "type")]
     pub type_field: Option<String>,
+
+    /// All other fields of the content item
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
 }
 
 /// User favorites model containing a user's favorite content
@@ -143,4 +143,12 @@ pub struct Track {
     /// Whether the track has parental content warnings
     #[serde(rename = "parental_warning")]
     pub parental_warning: Option<bool>,
+
+    /// Identifier of the playlist entry holding this track (only set for playlist tracks)
+    #[serde(rename = "playlist_track_id")]
+    pub playlist_track_id: Option<i64>,
+
+    /// Position of the track within its playlist (only set for playlist tracks)
+    #[serde(rename = "position")]
+    pub position: Option<i32>,
 }
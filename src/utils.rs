@@ -2,6 +2,7 @@ use std::{
     env::var,
     fs::{read_to_string, write},
     path::Path,
+    sync::LazyLock,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
@@ -780,3 +781,278 @@ pub fn timestamp_to_date_and_year(timestamp: i64) -> (Option<String>, Option<u32
         (Some(date_str), Some(year as u32))
     }
 }
+
+/// Converts HTML content, such as artist biographies and album reviews, to plain text.
+///
+/// Tags are removed, paragraphs and line breaks are kept as line breaks, list items are
+/// prefixed with a dash or their number, and HTML entities are decoded. Runs of whitespace
+/// are collapsed as a browser would.
+///
+/// # Arguments
+///
+/// * `html` - The HTML content to convert
+///
+/// # Returns
+///
+/// A `String` containing the plain text
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::utils::html_to_text;
+///
+/// let html = "<p>Formed in <b>Abingdon</b> in 1985.</p><p>Rock &amp; roll<br>forever</p>";
+/// assert_eq!(html_to_text(html), "Formed in Abingdon in 1985.\n\nRock & roll\nforever");
+///
+/// let html = "<ul><li>OK Computer</li><li>Kid A</li></ul>";
+/// assert_eq!(html_to_text(html), "- OK Computer\n- Kid A");
+/// ```
+pub fn html_to_text(html: &str) -> String {
+    convert_html(html, false)
+}
+
+/// Converts HTML content, such as artist biographies and album reviews, to Markdown.
+///
+/// Paragraphs, line breaks, headings, bold and italic text, links and lists are converted
+/// to their Markdown equivalent, other tags are removed, and HTML entities are decoded.
+/// Markdown syntax characters in the text are escaped with a backslash.
+///
+/// # Arguments
+///
+/// * `html` - The HTML content to convert
+///
+/// # Returns
+///
+/// A `String` containing the Markdown
+///
+/// # Examples
+///
+/// ```
+/// use qobuz_api_rust::utils::html_to_markdown;
+///
+/// let html = "<h2>Review</h2><p>A <em>landmark</em> album, see \
+///             <a href=\"https://www.qobuz.com\">Qobuz</a>.</p>";
+/// assert_eq!(
+///     html_to_markdown(html),
+///     "## Review\n\nA *landmark* album, see [Qobuz](https://www.qobuz.com)."
+/// );
+///
+/// let html = "<ol><li>Airbag</li><li><strong>Paranoid Android</strong></li></ol>";
+/// assert_eq!(html_to_markdown(html), "1. Airbag\n2. **Paranoid Android**");
+///
+/// let html = "<p>Released on *NSYNC_Records [US]</p>";
+/// assert_eq!(html_to_markdown(html), "Released on \\*NSYNC\\_Records \\[US\\]");
+/// ```
+pub fn html_to_markdown(html: &str) -> String {
+    convert_html(html, true)
+}
+
+/// Converts HTML to plain text or Markdown, as used by [`html_to_text`] and
+/// [`html_to_markdown`].
+fn convert_html(html: &str, markdown: bool) -> String {
+    let mut output = String::new();
+    let mut links: Vec<Option<String>> = Vec::new();
+    // The number of the next item of each open list, `None` for unordered lists
+    let mut lists: Vec<Option<usize>> = Vec::new();
+    let mut skip_content = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        if !skip_content {
+            push_html_text(&mut output, &rest[..start], markdown);
+        }
+
+        let Some(length) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + length];
+        rest = &rest[start + length + 1..];
+
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        match name.as_str() {
+            "script" | "style" => skip_content = !closing,
+            "br" => {
+                trim_trailing_spaces(&mut output);
+                output.push_str(if markdown { "  \n" } else { "\n" });
+            }
+            "p" | "div" | "blockquote" => push_block_break(&mut output),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                push_block_break(&mut output);
+                if markdown && !closing {
+                    let level = name[1..].parse().unwrap_or(1);
+                    output.push_str(&"#".repeat(level));
+                    output.push(' ');
+                }
+            }
+            "b" | "strong" if markdown => output.push_str("**"),
+            "i" | "em" if markdown => output.push('*'),
+            "a" if markdown => {
+                if closing {
+                    if let Some(Some(href)) = links.pop() {
+                        output.push_str(&format!("]({})", href));
+                    }
+                } else {
+                    let href = html_href(tag);
+                    if href.is_some() {
+                        output.push('[');
+                    }
+                    links.push(href);
+                }
+            }
+            "ul" | "ol" => {
+                if closing {
+                    lists.pop();
+                } else {
+                    lists.push((name == "ol").then_some(1));
+                }
+                if lists.is_empty() {
+                    push_block_break(&mut output);
+                }
+            }
+            "li" if !closing => {
+                trim_trailing_spaces(&mut output);
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                output.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        output.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => output.push_str("- "),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !skip_content {
+        push_html_text(&mut output, rest, markdown);
+    }
+
+    output
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Appends HTML text to the output, decoding entities and collapsing whitespace.
+///
+/// With `markdown`, the characters that Markdown would read as formatting are escaped.
+fn push_html_text(output: &mut String, text: &str, markdown: bool) {
+    for c in decode_html_entities(text).chars() {
+        if c.is_whitespace() {
+            if !output.is_empty() && !output.ends_with(char::is_whitespace) {
+                output.push(' ');
+            }
+        } else {
+            if markdown && matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+                output.push('\\');
+            }
+            output.push(c);
+        }
+    }
+}
+
+/// Ends the current block of the output with an empty line.
+fn push_block_break(output: &mut String) {
+    trim_trailing_spaces(output);
+    if output.is_empty() {
+        return;
+    }
+    while !output.ends_with("\n\n") {
+        output.push('\n');
+    }
+}
+
+/// Removes the spaces at the end of the output, keeping line breaks.
+fn trim_trailing_spaces(output: &mut String) {
+    let trimmed_length = output.trim_end_matches([' ', '\t']).len();
+    output.truncate(trimmed_length);
+}
+
+/// Returns the value of the `href` attribute of an HTML tag, if present.
+fn html_href(tag: &str) -> Option<String> {
+    static HREF: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(?i)(?:^|\s)href\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#)
+            .expect("the href pattern is valid")
+    });
+
+    let captures = HREF.captures(tag)?;
+    let value = captures
+        .get(1)
+        .or_else(|| captures.get(2))
+        .or_else(|| captures.get(3))?;
+
+    Some(decode_html_entities(value.as_str()))
+}
+
+/// Decodes the named and numeric HTML entities commonly found in editorial content.
+fn decode_html_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "hellip" => Some('…'),
+            "ndash" => Some('–'),
+            "mdash" => Some('—'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            "laquo" => Some('«'),
+            "raquo" => Some('»'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}